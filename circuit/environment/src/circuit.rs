@@ -13,9 +13,10 @@
 // limitations under the License.
 
 use crate::{helpers::Constraint, Mode, *};
+use snarkvm_utilities::ToBits;
 
 use core::{cell::RefCell, fmt};
-use std::rc::Rc;
+use std::{collections::HashMap, rc::Rc};
 
 type Field = <console::Testnet3 as console::Environment>::Field;
 
@@ -61,6 +62,18 @@ impl Environment for Circuit {
         })
     }
 
+    /// Returns new constants with the given values, allocated in a single pass.
+    fn new_constants(values: &[Self::BaseField]) -> Vec<Variable<Self::BaseField>> {
+        IN_WITNESS.with(|in_witness| {
+            // Ensure we are not in witness mode.
+            if !(*(**in_witness).borrow()) {
+                CIRCUIT.with(|circuit| (**circuit).borrow_mut().new_constants(values))
+            } else {
+                Self::halt("Tried to initialize new constants in witness mode")
+            }
+        })
+    }
+
     /// Returns a new witness of the given mode and value.
     fn new_witness<Fn: FnOnce() -> Output::Primitive, Output: Inject>(mode: Mode, logic: Fn) -> Output {
         IN_WITNESS.with(|in_witness| {
@@ -299,6 +312,30 @@ impl Environment for Circuit {
         })
     }
 
+    /// Returns the little-endian bits of every variable in the circuit, without resetting the circuit.
+    /// The variables are named by their mode and index, i.e. `constant_{i}`, `public_{i}`, and `private_{i}`.
+    fn eject_all_values() -> HashMap<String, Vec<bool>> {
+        CIRCUIT.with(|circuit| {
+            let circuit = (**circuit).borrow();
+            // Prepare the variables, with their name prefixes.
+            let variables = [
+                ("constant", circuit.to_constants()),
+                ("public", circuit.to_public_variables()),
+                ("private", circuit.to_private_variables()),
+            ];
+            // Eject the value of each variable.
+            variables
+                .into_iter()
+                .flat_map(|(prefix, variables)| {
+                    variables
+                        .iter()
+                        .enumerate()
+                        .map(move |(index, variable)| (format!("{prefix}_{index}"), variable.value().to_bits_le()))
+                })
+                .collect()
+        })
+    }
+
     /// Clears the circuit and initializes an empty environment.
     fn reset() {
         CIRCUIT.with(|circuit| {
@@ -352,6 +389,31 @@ mod tests {
         println!("{output}");
     }
 
+    #[test]
+    fn test_eject_all_values() {
+        let one = snarkvm_console_types::Field::<<Circuit as Environment>::Network>::one();
+        let two = one + one;
+
+        // Inject a few variables.
+        let _constant = Field::<Circuit>::new(Mode::Constant, two);
+        let _public = Field::<Circuit>::new(Mode::Public, one);
+        let _private = Field::<Circuit>::new(Mode::Private, two);
+
+        // Eject the values.
+        let values = Circuit::eject_all_values();
+        assert_eq!(4, values.len());
+        assert_eq!(values["constant_0"], two.to_bits_le());
+        assert_eq!(values["public_0"], one.to_bits_le());
+        assert_eq!(values["public_1"], one.to_bits_le());
+        assert_eq!(values["private_0"], two.to_bits_le());
+
+        // Ensure the circuit is not reset.
+        assert_eq!(1, Circuit::num_constants());
+        assert_eq!(2, Circuit::num_public());
+        assert_eq!(1, Circuit::num_private());
+        Circuit::reset();
+    }
+
     #[test]
     fn test_circuit_scope() {
         Circuit::scope("test_circuit_scope", || {
@@ -17,6 +17,7 @@ use snarkvm_curves::AffineCurve;
 use snarkvm_fields::traits::*;
 
 use core::{fmt, hash};
+use std::collections::HashMap;
 
 pub trait Environment: 'static + Copy + Clone + fmt::Debug + fmt::Display + Eq + PartialEq + hash::Hash {
     type Network: console::Network<Affine = Self::Affine, Field = Self::BaseField, Scalar = Self::ScalarField>;
@@ -51,6 +52,9 @@ pub trait Environment: 'static + Copy + Clone + fmt::Debug + fmt::Display + Eq +
     /// Returns a new variable of the given mode and value.
     fn new_variable(mode: Mode, value: Self::BaseField) -> Variable<Self::BaseField>;
 
+    /// Returns new constants with the given values, allocated in a single pass.
+    fn new_constants(values: &[Self::BaseField]) -> Vec<Variable<Self::BaseField>>;
+
     /// Returns a new witness of the given mode and value.
     fn new_witness<Fn: FnOnce() -> Output::Primitive, Output: Inject>(mode: Mode, value: Fn) -> Output;
 
@@ -169,6 +173,10 @@ pub trait Environment: 'static + Copy + Clone + fmt::Debug + fmt::Display + Eq +
     /// Returns the R1CS assignment of the circuit, resetting the circuit.
     fn eject_assignment_and_reset() -> Assignment<<Self::Network as console::Environment>::Field>;
 
+    /// Returns the little-endian bits of every variable in the circuit, without resetting the circuit.
+    /// The variables are named by their mode and index, i.e. `constant_{i}`, `public_{i}`, and `private_{i}`.
+    fn eject_all_values() -> HashMap<String, Vec<bool>>;
+
     /// Clears and initializes an empty environment.
     fn reset();
 }
@@ -115,6 +115,11 @@ impl<F: PrimeField> Counter<F> {
         self.constants += 1;
     }
 
+    /// Increments the number of constants by the given amount.
+    pub(crate) fn increment_constants(&mut self, num_constants: u64) {
+        self.constants += num_constants;
+    }
+
     /// Increments the number of public variables by 1.
     pub(crate) fn increment_public(&mut self) {
         self.public += 1;
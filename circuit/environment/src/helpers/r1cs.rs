@@ -63,6 +63,14 @@ impl<F: PrimeField> R1CS<F> {
         variable
     }
 
+    /// Returns new constants with the given values and scope, reserving space for all of them at once.
+    pub(crate) fn new_constants(&mut self, values: &[F]) -> Vec<Variable<F>> {
+        let variables = values.iter().map(|value| Variable::Constant(Rc::new(*value))).collect::<Vec<_>>();
+        self.constants.extend_from_slice(&variables);
+        self.counter.increment_constants(variables.len() as u64);
+        variables
+    }
+
     /// Returns a new public variable with the given value and scope.
     pub(crate) fn new_public(&mut self, value: F) -> Variable<F> {
         let variable = Variable::Public(self.public.len() as u64, Rc::new(value));
@@ -155,6 +163,11 @@ impl<F: PrimeField> R1CS<F> {
         self.counter.num_nonzeros_in_scope()
     }
 
+    /// Returns the constants in the constraint system.
+    pub(crate) fn to_constants(&self) -> &Vec<Variable<F>> {
+        &self.constants
+    }
+
     /// Returns the public variables in the constraint system.
     pub(crate) fn to_public_variables(&self) -> &Vec<Variable<F>> {
         &self.public
@@ -19,7 +19,13 @@ pub mod v0;
 pub use v0::*;
 
 use snarkvm_circuit_collections::merkle_tree::MerklePath;
-use snarkvm_circuit_types::{environment::Environment, Boolean, Field, Group, Scalar};
+use snarkvm_circuit_types::{
+    environment::{Environment, Inject},
+    Boolean,
+    Field,
+    Group,
+    Scalar,
+};
 
 pub trait Aleo: Environment {
     /// The maximum number of field elements in data (must not exceed u16::MAX).
@@ -34,6 +40,9 @@ pub trait Aleo: Environment {
     /// Returns the serial number domain as a constant field element.
     fn serial_number_domain() -> Field<Self>;
 
+    /// Returns the given field elements as constant circuit fields, allocated in a single pass.
+    fn inject_batch(constants: &[<Field<Self> as Inject>::Primitive]) -> Vec<Field<Self>>;
+
     /// Returns the scalar multiplication on the generator `G`.
     fn g_scalar_multiply(scalar: &Scalar<Self>) -> Group<Self>;
 
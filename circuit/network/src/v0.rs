@@ -41,6 +41,7 @@ use snarkvm_circuit_types::{
 };
 
 use core::fmt;
+use std::collections::HashMap;
 
 type E = Circuit;
 
@@ -96,6 +97,14 @@ impl Aleo for AleoV0 {
         SERIAL_NUMBER_DOMAIN.with(|domain| domain.clone())
     }
 
+    /// Returns the given field elements as constant circuit fields, allocated in a single pass.
+    fn inject_batch(constants: &[<Field<Self> as Inject>::Primitive]) -> Vec<Field<Self>> {
+        // Prepare the base field elements.
+        let values = constants.iter().map(|constant| **constant).collect::<Vec<_>>();
+        // Allocate all of the constants at once, and convert them into circuit fields.
+        Self::new_constants(&values).into_iter().map(|variable| Field::from(LinearCombination::from(variable))).collect()
+    }
+
     /// Returns the scalar multiplication on the generator `G`.
     #[inline]
     fn g_scalar_multiply(scalar: &Scalar<Self>) -> Group<Self> {
@@ -327,6 +336,11 @@ impl Environment for AleoV0 {
         E::new_variable(mode, value)
     }
 
+    /// Returns new constants with the given values, allocated in a single pass.
+    fn new_constants(values: &[Self::BaseField]) -> Vec<Variable<Self::BaseField>> {
+        E::new_constants(values)
+    }
+
     /// Returns a new witness of the given mode and value.
     fn new_witness<Fn: FnOnce() -> Output::Primitive, Output: Inject>(mode: Mode, logic: Fn) -> Output {
         E::new_witness(mode, logic)
@@ -431,6 +445,11 @@ impl Environment for AleoV0 {
         E::eject_assignment_and_reset()
     }
 
+    /// Returns the little-endian bits of every variable in the circuit, without resetting the circuit.
+    fn eject_all_values() -> HashMap<String, Vec<bool>> {
+        E::eject_all_values()
+    }
+
     /// Clears the circuit and initializes an empty environment.
     fn reset() {
         E::reset()
@@ -448,6 +467,7 @@ impl Display for AleoV0 {
 mod tests {
     use super::*;
     use snarkvm_circuit_types::Field;
+    use snarkvm_console_types::prelude::{TestRng, Uniform};
 
     type CurrentAleo = AleoV0;
 
@@ -483,6 +503,33 @@ mod tests {
         println!("{output}");
     }
 
+    #[test]
+    fn test_inject_batch() {
+        let mut rng = TestRng::default();
+
+        // Sample the constants.
+        let constants = (0..100).map(|_| Uniform::rand(&mut rng)).collect::<Vec<_>>();
+
+        CurrentAleo::scope("test_inject_batch", || {
+            // Inject the constants.
+            let fields = CurrentAleo::inject_batch(&constants);
+            assert_eq!(constants.len(), fields.len());
+
+            // Ensure the injected values eject correctly.
+            for (constant, field) in constants.iter().zip_eq(&fields) {
+                assert_eq!(*constant, field.eject_value());
+                assert!(field.is_constant());
+            }
+
+            // Ensure the constants are allocated, and no constraints are added.
+            assert_eq!(constants.len() as u64, CurrentAleo::num_constants_in_scope());
+            assert_eq!(0, CurrentAleo::num_public_in_scope());
+            assert_eq!(0, CurrentAleo::num_private_in_scope());
+            assert_eq!(0, CurrentAleo::num_constraints_in_scope());
+        });
+        CurrentAleo::reset();
+    }
+
     #[test]
     fn test_circuit_scope() {
         CurrentAleo::scope("test_circuit_scope", || {
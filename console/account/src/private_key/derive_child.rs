@@ -0,0 +1,100 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+static ACCOUNT_CHILD_DOMAIN: &str = "AleoAccountChildKey0";
+
+impl<N: Network> PrivateKey<N> {
+    /// Returns the (hardened) child private key at the given index, where:
+    ///     tweak := Hash(domain, seed, index)
+    ///     child_seed := seed + tweak
+    ///
+    /// Note: The tweak is derived from the parent seed, and never from public key material,
+    /// so that a leaked child private key does not reveal the parent private key.
+    /// For this reason, there is intentionally no `hardened` flag for non-hardened derivation:
+    /// a tweak derived from the parent public key is public, so `child_seed - tweak` would recover the parent seed.
+    pub fn derive_child(&self, index: u32) -> Result<Self> {
+        // Construct the child domain separator.
+        let domain = Field::<N>::new_domain_separator(ACCOUNT_CHILD_DOMAIN);
+        // Encode the index.
+        let index = Field::from_u32(index);
+
+        // Compute the tweak, and add it to the parent seed.
+        let tweak = N::hash_psd4(&[domain, self.seed, index])?;
+        // Derive the child private key from the child seed.
+        Self::try_from(self.seed + tweak)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_derive_child() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a new parent private key.
+            let parent = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+
+            // Ensure the derivation is deterministic.
+            let child_0 = parent.derive_child(0)?;
+            assert_eq!(child_0, parent.derive_child(0)?);
+            // Ensure distinct indices produce distinct children.
+            let child_1 = parent.derive_child(1)?;
+            assert_ne!(child_0, child_1);
+            assert_ne!(parent, child_0);
+            // Ensure the child is a well-formed private key.
+            assert_eq!(child_0, PrivateKey::try_from(child_0.seed())?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_derive_child_does_not_leak_parent() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        let domain = Field::<CurrentNetwork>::new_domain_separator(ACCOUNT_CHILD_DOMAIN);
+
+        for _ in 0..ITERATIONS {
+            // Sample a new parent private key.
+            let parent = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+            let index = 7u32;
+
+            // Leak the child private key.
+            let child = parent.derive_child(index)?;
+
+            // Ensure the tweak formula is the one used in the derivation, by recovering the parent seed with it.
+            let tweak = CurrentNetwork::hash_psd4(&[domain, parent.seed(), Field::from_u32(index)])?;
+            assert_eq!(child.seed() - tweak, parent.seed());
+
+            // Attempt to recover the parent seed by applying the same tweak formula to the leaked child seed,
+            // as the parent seed is not known.
+            let tweak = CurrentNetwork::hash_psd4(&[domain, child.seed(), Field::from_u32(index)])?;
+            let candidate = child.seed() - tweak;
+
+            // Ensure the parent seed is not recovered.
+            assert_ne!(candidate, parent.seed());
+            assert_ne!(PrivateKey::try_from(candidate).ok(), Some(parent));
+        }
+        Ok(())
+    }
+}
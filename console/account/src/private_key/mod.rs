@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod bytes;
+mod derive_child;
 mod serialize;
 mod string;
 mod try_from;
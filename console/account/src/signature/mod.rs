@@ -14,6 +14,7 @@
 
 mod bytes;
 mod parse;
+mod recovery;
 mod serialize;
 mod verify;
 
@@ -27,7 +28,7 @@ use crate::PrivateKey;
 
 use crate::address::Address;
 use snarkvm_console_network::prelude::*;
-use snarkvm_console_types::{Field, Scalar};
+use snarkvm_console_types::{Field, Group, Scalar};
 
 #[derive(Copy, Clone, PartialEq, Eq, Hash)]
 pub struct Signature<N: Network> {
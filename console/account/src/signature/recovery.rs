@@ -0,0 +1,116 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Signature<N> {
+    /// Returns the recovery data `(response, nonce, recovery_id)` for the signature, where:
+    ///     nonce := (response * G) + (challenge * pk_sig)
+    ///     recovery_id := the parity of the y-coordinate of the nonce
+    pub fn to_recovery_data(&self) -> (Scalar<N>, Group<N>, u8) {
+        // Compute the nonce `g_r` := (response * G) + (challenge * pk_sig).
+        let nonce = N::g_scalar_multiply(&self.response) + (self.compute_key.pk_sig() * self.challenge);
+        // Compute the recovery ID.
+        let recovery_id = Self::recovery_id(&nonce);
+        // Return the recovery data.
+        (self.response, nonce, recovery_id)
+    }
+
+    /// Returns the signature from the recovery data `(response, nonce, recovery_id)`.
+    ///
+    /// Note: The challenge commits to the signer's compute key and the signed message,
+    /// so both are required to reconstruct the signature.
+    pub fn from_recovery_data(
+        response: Scalar<N>,
+        nonce: Group<N>,
+        recovery_id: u8,
+        compute_key: &ComputeKey<N>,
+        message: &[Field<N>],
+    ) -> Result<Self> {
+        // Ensure the recovery ID is well-formed.
+        ensure!(recovery_id <= 1, "Invalid recovery ID '{recovery_id}': expected 0 or 1");
+        // Ensure the recovery ID matches the nonce.
+        ensure!(recovery_id == Self::recovery_id(&nonce), "The recovery ID does not match the signature nonce");
+        // Ensure the number of field elements does not exceed the maximum allowed size.
+        if message.len() > N::MAX_DATA_SIZE_IN_FIELDS as usize {
+            bail!("Cannot recover the signature: the message exceeds maximum allowed size")
+        }
+
+        // Retrieve pk_sig.
+        let pk_sig = compute_key.pk_sig();
+        // Retrieve pr_sig.
+        let pr_sig = compute_key.pr_sig();
+
+        // Derive the address from the compute key.
+        let address = Address::try_from(compute_key)?;
+
+        // Construct the hash input as (r * G, pk_sig, pr_sig, address, message).
+        let mut preimage = Vec::with_capacity(4 + message.len());
+        preimage.extend([nonce, pk_sig, pr_sig, *address].map(|point| point.to_x_coordinate()));
+        preimage.extend(message);
+
+        // Compute the verifier challenge.
+        let challenge = N::hash_to_scalar_psd8(&preimage)?;
+
+        // Output the signature.
+        Ok(Self { challenge, response, compute_key: *compute_key })
+    }
+
+    /// Returns the recovery ID for the given nonce, which is the parity of its y-coordinate.
+    fn recovery_id(nonce: &Group<N>) -> u8 {
+        nonce.to_y_coordinate().to_bits_le()[0] as u8
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "private_key")]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_recovery_data() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample an address and a private key.
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+            let address = Address::try_from(&private_key)?;
+
+            // Generate a signature.
+            let message: Vec<_> = (0..i).map(|_| Uniform::rand(&mut rng)).collect();
+            let signature = Signature::sign(&private_key, &message, &mut rng)?;
+
+            // Recover the signature.
+            let (response, nonce, recovery_id) = signature.to_recovery_data();
+            assert!(recovery_id <= 1);
+            let candidate =
+                Signature::from_recovery_data(response, nonce, recovery_id, &signature.compute_key(), &message)?;
+            assert_eq!(signature, candidate);
+            assert!(candidate.verify(&address, &message));
+
+            // Ensure a mismatched or malformed recovery ID fails.
+            assert!(
+                Signature::from_recovery_data(response, nonce, 1 - recovery_id, &signature.compute_key(), &message)
+                    .is_err()
+            );
+            assert!(Signature::from_recovery_data(response, nonce, 2, &signature.compute_key(), &message).is_err());
+        }
+        Ok(())
+    }
+}
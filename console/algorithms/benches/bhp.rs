@@ -65,10 +65,22 @@ fn bhp1024(c: &mut Criterion) {
     c.bench_function(&format!("BHP1024 Hash - input size {}", input.len()), |b| b.iter(|| hash.hash(&input)));
 }
 
+fn bhp256_batch(c: &mut Criterion) {
+    let rng = &mut TestRng::default();
+    let hash = BHP256::<Console>::setup("BHP256").unwrap();
+
+    for num_inputs in [1, 10, 100] {
+        let inputs = (0..num_inputs).map(|_| (0..256).map(|_| bool::rand(rng)).collect::<Vec<_>>()).collect::<Vec<_>>();
+        c.bench_function(&format!("BHP256 Batch Hash - {num_inputs} inputs of size 256"), |b| {
+            b.iter(|| hash.batch_hash(&inputs))
+        });
+    }
+}
+
 criterion_group! {
     name = bhp;
     config = Criterion::default().sample_size(1000);
-    targets = bhp256, bhp512, bhp768, bhp1024
+    targets = bhp256, bhp512, bhp768, bhp1024, bhp256_batch
 }
 
 criterion_main!(bhp);
@@ -0,0 +1,80 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHP<E, NUM_WINDOWS, WINDOW_SIZE> {
+    /// Returns the BHP hash of each of the given inputs as a field element.
+    /// The hashes are computed in parallel, and returned in the order of the given inputs.
+    pub fn batch_hash(&self, inputs: &[Vec<bool>]) -> Result<Vec<Field<E>>> {
+        cfg_iter!(inputs).map(|input| self.hash(input)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_types::environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: u64 = 100;
+
+    macro_rules! check_batch_hash {
+        ($bhp:ident) => {{
+            let bhp = $bhp::<CurrentEnvironment>::setup("BHPTest")?;
+
+            let mut rng = TestRng::default();
+
+            // Sample inputs of varying lengths.
+            let inputs = (0..ITERATIONS)
+                .map(|i| (0..bhp.window_size() as u64 + i).map(|_| bool::rand(&mut rng)).collect::<Vec<_>>())
+                .collect::<Vec<_>>();
+
+            // Ensure each batched hash matches the individually-computed hash.
+            let candidate = bhp.batch_hash(&inputs)?;
+            assert_eq!(inputs.len(), candidate.len());
+            for (input, candidate) in inputs.iter().zip_eq(candidate) {
+                assert_eq!(bhp.hash(input)?, candidate);
+            }
+
+            // Ensure an empty batch produces no hashes.
+            assert!(bhp.batch_hash(&[])?.is_empty());
+            Ok(())
+        }};
+    }
+
+    #[test]
+    fn test_bhp256_batch_hash() -> Result<()> {
+        check_batch_hash!(BHP256)
+    }
+
+    #[test]
+    fn test_bhp512_batch_hash() -> Result<()> {
+        check_batch_hash!(BHP512)
+    }
+
+    #[test]
+    fn test_bhp768_batch_hash() -> Result<()> {
+        check_batch_hash!(BHP768)
+    }
+
+    #[test]
+    fn test_bhp1024_batch_hash() -> Result<()> {
+        check_batch_hash!(BHP1024)
+    }
+}
@@ -15,6 +15,7 @@
 pub mod hasher;
 use hasher::BHPHasher;
 
+mod batch_hash;
 mod commit;
 mod commit_uncompressed;
 mod hash;
@@ -98,6 +98,8 @@ pub trait Network:
     const STARTING_SUPPLY: u64 = 1_500_000_000_000_000; // 1.5B credits
     /// The cost in microcredits per byte for the deployment transaction.
     const DEPLOYMENT_FEE_MULTIPLIER: u64 = 1_000; // 1 millicredit per byte
+    /// The estimated number of constraints proven per millisecond.
+    const CONSTRAINTS_PER_MS: u64 = 25; // 25 constraints per millisecond
 
     /// The anchor time per block in seconds, which must be greater than the round time per block.
     const ANCHOR_TIME: u16 = 25;
@@ -301,6 +303,17 @@ pub trait Network:
     /// Returns the Poseidon hash with an input rate of 8 on the scalar field.
     fn hash_to_scalar_psd8(input: &[Field<Self>]) -> Result<Scalar<Self>>;
 
+    /// Returns the SHA-256 hash as a little-endian integer, reduced modulo the field.
+    fn hash_sha256_to_field(input: &[u8]) -> Result<Field<Self>>;
+
+    /// Returns the Keccak hash with a 256-bit output, as used by Ethereum.
+    #[cfg(feature = "ethereum-compat")]
+    fn hash_keccak256(input: &[u8]) -> [u8; 32];
+
+    /// Returns the Keccak hash with a 512-bit output.
+    #[cfg(feature = "ethereum-compat")]
+    fn hash_keccak512(input: &[u8]) -> [u8; 64];
+
     /// Returns a Merkle tree with a BHP leaf hasher of 1024-bits and a BHP path hasher of 512-bits.
     fn merkle_tree_bhp<const DEPTH: u8>(leaves: &[Vec<bool>]) -> Result<BHPMerkleTree<Self, DEPTH>>;
 
@@ -322,4 +335,18 @@ pub trait Network:
         root: &Field<Self>,
         leaf: &Vec<Field<Self>>,
     ) -> bool;
+
+    /// Returns `true` if the given proof-of-work hash meets the proof target (inclusive),
+    /// where the target of a hash is `u64::MAX / proof_hash`, as in `PartialSolution::to_target`.
+    /// Note: The proof-of-work hash is the `u64` that `PartialSolution::to_proof_hash` derives from the
+    /// puzzle commitment, rather than a field element, as the proof target is only defined over `u64`.
+    fn verify_proof_of_work(proof_target: u64, proof_hash: u64) -> bool {
+        // Compute the target of the proof-of-work hash.
+        let target = match proof_hash {
+            0 => u64::MAX,
+            proof_hash => u64::MAX / proof_hash,
+        };
+        // Ensure the target meets the proof target.
+        target >= proof_target
+    }
 }
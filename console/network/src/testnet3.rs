@@ -412,6 +412,37 @@ impl Network for Testnet3 {
         POSEIDON_8.hash_to_scalar(input)
     }
 
+    /// Returns the SHA-256 hash as a little-endian integer, reduced modulo the field.
+    fn hash_sha256_to_field(input: &[u8]) -> Result<Field<Self>> {
+        use sha2::{Digest, Sha256};
+
+        Ok(Field::new(<Self as Environment>::Field::from_bytes_le_mod_order(&Sha256::digest(input))))
+    }
+
+    /// Returns the Keccak hash with a 256-bit output, as used by Ethereum.
+    #[cfg(feature = "ethereum-compat")]
+    fn hash_keccak256(input: &[u8]) -> [u8; 32] {
+        use tiny_keccak::{Hasher, Keccak};
+
+        let mut output = [0u8; 32];
+        let mut keccak = Keccak::v256();
+        keccak.update(input);
+        keccak.finalize(&mut output);
+        output
+    }
+
+    /// Returns the Keccak hash with a 512-bit output.
+    #[cfg(feature = "ethereum-compat")]
+    fn hash_keccak512(input: &[u8]) -> [u8; 64] {
+        use tiny_keccak::{Hasher, Keccak};
+
+        let mut output = [0u8; 64];
+        let mut keccak = Keccak::v512();
+        keccak.update(input);
+        keccak.finalize(&mut output);
+        output
+    }
+
     /// Returns a Merkle tree with a BHP leaf hasher of 1024-bits and a BHP path hasher of 512-bits.
     fn merkle_tree_bhp<const DEPTH: u8>(leaves: &[Vec<bool>]) -> Result<BHPMerkleTree<Self, DEPTH>> {
         MerkleTree::new(&*BHP_1024, &*BHP_512, leaves)
@@ -454,4 +485,77 @@ mod tests {
         let group = CurrentNetwork::g_scalar_multiply(&scalar);
         assert_eq!(group, CurrentNetwork::g_powers()[0] * scalar);
     }
+
+    #[test]
+    fn test_hash_sha256_to_field() -> Result<()> {
+        // The SHA-256 digests of "" and "abc" both exceed the field modulus, and are reduced.
+        let vectors = [
+            ("", "4994414173084033805147437496434724128381958513963093106226847356201711481055field"),
+            ("abc", "2287077383037410943824832303114059112908187086552034575215272969824751745201field"),
+        ];
+        for (input, expected) in vectors {
+            let candidate = CurrentNetwork::hash_sha256_to_field(input.as_bytes())?;
+            assert_eq!(Field::<CurrentNetwork>::from_str(expected)?, candidate);
+        }
+
+        // Ensure the hash is deterministic, and distinct for distinct inputs.
+        let mut rng = TestRng::default();
+        for _ in 0..100 {
+            let input = (0..32).map(|_| u8::rand(&mut rng)).collect::<Vec<_>>();
+            let candidate = CurrentNetwork::hash_sha256_to_field(&input)?;
+            assert_eq!(candidate, CurrentNetwork::hash_sha256_to_field(&input)?);
+            assert_ne!(candidate, CurrentNetwork::hash_sha256_to_field(&input[1..])?);
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "ethereum-compat")]
+    #[test]
+    fn test_hash_keccak256() {
+        // Test vectors from the Ethereum Keccak-256 implementation.
+        let vectors = [
+            ("", "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"),
+            ("abc", "4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45"),
+            (
+                "The quick brown fox jumps over the lazy dog",
+                "4d741b6f1eb29cb2a9b9911c82f56fa8d73b04959d3d9d222895df6c0b28aa15",
+            ),
+        ];
+        for (input, expected) in vectors {
+            assert_eq!(expected, hex::encode(CurrentNetwork::hash_keccak256(input.as_bytes())));
+        }
+    }
+
+    #[cfg(feature = "ethereum-compat")]
+    #[test]
+    fn test_hash_keccak512() {
+        // Test vectors from the original Keccak-512 submission.
+        let vectors = [
+            (
+                "",
+                "0eab42de4c3ceb9235fc91acffe746b29c29a8c366b7c60e4e67c466f36a4304c00fa9caf9d87976ba469bcbe06713b435f091ef2769fb160cdab33d3670680e",
+            ),
+            (
+                "abc",
+                "18587dc2ea106b9a1563e32b3312421ca164c7f1f07bc922a9c83d77cea3a1e5d0c69910739025372dc14ac9642629379540c17e2a65b19d77aa511a9d00bb96",
+            ),
+        ];
+        for (input, expected) in vectors {
+            assert_eq!(expected, hex::encode(CurrentNetwork::hash_keccak512(input.as_bytes())));
+        }
+    }
+
+    #[test]
+    fn test_verify_proof_of_work() {
+        let proof_target = CurrentNetwork::GENESIS_PROOF_TARGET;
+
+        // Ensure a hash with a target above the proof target passes.
+        assert!(CurrentNetwork::verify_proof_of_work(proof_target, u64::MAX / (proof_target + 1)));
+        // Ensure a hash with a target exactly at the proof target passes.
+        assert!(CurrentNetwork::verify_proof_of_work(proof_target, u64::MAX / proof_target));
+        // Ensure a hash with a target below the proof target fails.
+        assert!(!CurrentNetwork::verify_proof_of_work(proof_target, u64::MAX / proof_target + 1));
+        // Ensure the zero hash meets any proof target.
+        assert!(CurrentNetwork::verify_proof_of_work(u64::MAX, 0));
+    }
 }
@@ -0,0 +1,54 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Plaintext<N> {
+    /// Returns the BHP hash of the plaintext.
+    pub fn hash(&self) -> Result<Field<N>> {
+        N::hash_bhp256(&self.to_bits_le())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_hash() -> Result<()> {
+        let plaintext = Plaintext::<CurrentNetwork>::from_str(
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah, amount: 100u64 }",
+        )?;
+
+        // Ensure equal plaintexts produce equal hashes.
+        let candidate = Plaintext::<CurrentNetwork>::from_str(
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah, amount: 100u64 }",
+        )?;
+        assert_eq!(plaintext.hash()?, candidate.hash()?);
+
+        // Ensure changing one entry changes the hash.
+        let candidate = Plaintext::<CurrentNetwork>::from_str(
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah, amount: 101u64 }",
+        )?;
+        assert_ne!(plaintext.hash()?, candidate.hash()?);
+
+        // Ensure the hash of a literal matches the BHP hash of its bits.
+        let literal = Plaintext::<CurrentNetwork>::from_str("5field")?;
+        assert_eq!(literal.hash()?, CurrentNetwork::hash_bhp256(&literal.to_bits_le())?);
+        Ok(())
+    }
+}
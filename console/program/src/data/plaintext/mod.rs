@@ -18,6 +18,7 @@ mod equal;
 mod find;
 mod from_bits;
 mod from_fields;
+mod hash;
 mod num_randomizers;
 mod parse;
 mod serialize;
@@ -31,3 +31,90 @@ impl<N: Network> Visibility for Plaintext<N> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Samples a struct of strings holding `num_bytes` bytes in total, using maximum-length strings where possible.
+    fn sample_member(num_bytes: usize) -> Plaintext<CurrentNetwork> {
+        let string = "a".repeat(num_bytes);
+        let member = Plaintext::Struct(
+            string
+                .as_bytes()
+                .chunks(CurrentNetwork::MAX_STRING_BYTES as usize)
+                .enumerate()
+                .map(|(i, chunk)| {
+                    let identifier = Identifier::from_str(&format!("s{i}")).unwrap();
+                    let string = std::str::from_utf8(chunk).unwrap();
+                    (identifier, Plaintext::from(Literal::String(StringType::new(string))))
+                })
+                .collect(),
+            Default::default(),
+        );
+        // Cache the bits of the member, so that clones of it are cheap to encode.
+        member.to_bits_le();
+        member
+    }
+
+    /// Samples a struct with the given number of members, where each member is a struct of 30 maximum-length strings,
+    /// followed by one more member holding `num_extra_bytes` bytes of strings.
+    fn sample_plaintext(
+        member: &Plaintext<CurrentNetwork>,
+        num_members: usize,
+        num_extra_bytes: usize,
+    ) -> Plaintext<CurrentNetwork> {
+        Plaintext::Struct(
+            (0..num_members)
+                .map(|i| (Identifier::from_str(&format!("m{i}")).unwrap(), member.clone()))
+                .chain([(Identifier::from_str("extra").unwrap(), sample_member(num_extra_bytes))])
+                .collect(),
+            Default::default(),
+        )
+    }
+
+    /// Returns the expected number of field elements for the given plaintext.
+    fn expected_size_in_fields(plaintext: &Plaintext<CurrentNetwork>) -> usize {
+        let num_bits = plaintext.to_bits_le().len() + 1;
+        let data_bits = Field::<CurrentNetwork>::size_in_data_bits();
+        (num_bits + data_bits - 1) / data_bits
+    }
+
+    #[test]
+    fn test_size_in_fields() -> Result<()> {
+        // Ensure a small plaintext is sized correctly.
+        let plaintext = Plaintext::<CurrentNetwork>::from_str("{ owner: 0field, amount: 100u64 }")?;
+        assert_eq!(plaintext.size_in_fields()? as usize, expected_size_in_fields(&plaintext));
+
+        let max_size = CurrentNetwork::MAX_DATA_SIZE_IN_FIELDS as usize;
+        let member = sample_member(30 * CurrentNetwork::MAX_STRING_BYTES as usize);
+
+        // Ensure the base plaintext is within the limit, and one more full member exceeds it.
+        assert!(expected_size_in_fields(&sample_plaintext(&member, 16, 0)) < max_size);
+        assert!(expected_size_in_fields(&sample_plaintext(&member, 17, 0)) > max_size);
+
+        // Search for the smallest number of extra bytes for which the plaintext exceeds the limit.
+        let (mut low, mut high) = (0, 30 * CurrentNetwork::MAX_STRING_BYTES as usize);
+        while low < high {
+            let mid = (low + high) / 2;
+            match expected_size_in_fields(&sample_plaintext(&member, 16, mid)) > max_size {
+                true => high = mid,
+                false => low = mid + 1,
+            }
+        }
+
+        // Ensure a plaintext of exactly the maximum size is sized correctly.
+        let plaintext = sample_plaintext(&member, 16, low - 1);
+        assert_eq!(expected_size_in_fields(&plaintext), max_size);
+        assert_eq!(plaintext.size_in_fields()? as usize, max_size);
+
+        // Ensure a plaintext of one field element over the maximum size fails.
+        let plaintext = sample_plaintext(&member, 16, low);
+        assert_eq!(expected_size_in_fields(&plaintext), max_size + 1);
+        assert!(plaintext.size_in_fields().is_err());
+        Ok(())
+    }
+}
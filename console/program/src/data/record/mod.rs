@@ -33,6 +33,7 @@ mod tag;
 mod to_bits;
 mod to_commitment;
 mod to_fields;
+mod verify_nonce;
 
 use crate::{Ciphertext, Identifier, Literal, Plaintext, ProgramID};
 use snarkvm_console_account::{Address, PrivateKey, ViewKey};
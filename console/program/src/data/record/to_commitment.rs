@@ -17,12 +17,17 @@ use super::*;
 impl<N: Network> Record<N, Plaintext<N>> {
     /// Returns the record commitment.
     pub fn to_commitment(&self, program_id: &ProgramID<N>, record_name: &Identifier<N>) -> Result<Field<N>> {
+        // Compute the BHP hash of the program record.
+        N::hash_bhp1024(&self.to_commitment_inputs(program_id, record_name))
+    }
+
+    /// Returns the input to the record commitment, as `(program_id || record_name || record)`.
+    pub fn to_commitment_inputs(&self, program_id: &ProgramID<N>, record_name: &Identifier<N>) -> Vec<bool> {
         // Construct the input as `(program_id || record_name || record)`.
         let mut input = program_id.to_bits_le();
         input.extend(record_name.to_bits_le());
         input.extend(self.to_bits_le());
-        // Compute the BHP hash of the program record.
-        N::hash_bhp1024(&input)
+        input
     }
 }
 
@@ -32,3 +37,29 @@ impl<N: Network> Record<N, Ciphertext<N>> {
         bail!("Illegal operation: Record::to_commitment() cannot be invoked on the `Ciphertext` variant.")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_to_commitment_inputs() -> Result<()> {
+        let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, amount: 5u64.private, _nonce: 0group.public }",
+        )?;
+        let program_id = ProgramID::from_str("token.aleo")?;
+        let record_name = Identifier::from_str("token")?;
+
+        // Ensure the commitment inputs reproduce the commitment.
+        let inputs = record.to_commitment_inputs(&program_id, &record_name);
+        assert_eq!(CurrentNetwork::hash_bhp1024(&inputs)?, record.to_commitment(&program_id, &record_name)?);
+
+        // Ensure the commitment inputs depend on the record name.
+        let other_name = Identifier::from_str("credits")?;
+        assert_ne!(inputs, record.to_commitment_inputs(&program_id, &other_name));
+        Ok(())
+    }
+}
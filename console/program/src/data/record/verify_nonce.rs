@@ -0,0 +1,52 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network, Private: Visibility> Record<N, Private> {
+    /// Returns `true` if the nonce is in the prime-order subgroup.
+    pub fn verify_nonce(&self) -> bool {
+        self.nonce.is_in_prime_subgroup()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_verify_nonce() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample a nonce in the prime-order subgroup.
+        let nonce = Group::<CurrentNetwork>::generator() * Scalar::rand(rng);
+        let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(&format!(
+            "{{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, amount: 5u64.private, _nonce: {nonce}.public }}"
+        ))?;
+        assert!(record.verify_nonce());
+
+        // Add the point of order 2 to the nonce, which moves it out of the prime-order subgroup.
+        let low_order = Group::<CurrentNetwork>::from_xy_coordinates_unchecked(Field::zero(), -Field::one());
+        let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_plaintext(
+            record.owner().clone(),
+            record.data().clone(),
+            nonce + low_order,
+        )?;
+        assert!(!record.verify_nonce());
+        Ok(())
+    }
+}
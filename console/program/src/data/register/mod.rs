@@ -37,6 +37,22 @@ impl<N: Network> Register<N> {
             Self::Member(locator, _) => *locator,
         }
     }
+
+    /// Returns `true` if the register is a member access, i.e. `r0.owner`.
+    #[inline]
+    pub const fn is_access(&self) -> bool {
+        matches!(self, Self::Member(..))
+    }
+
+    /// Returns the identifier(s) of the member access, if the register is a member access.
+    /// For example, `r0.a.b` returns `[a, b]`, and `r0` returns `None`.
+    #[inline]
+    pub fn access_path(&self) -> Option<&[Identifier<N>]> {
+        match self {
+            Self::Locator(..) => None,
+            Self::Member(_, identifiers) => Some(identifiers),
+        }
+    }
 }
 
 impl<N: Network> Ord for Register<N> {
@@ -60,6 +76,24 @@ mod tests {
 
     type CurrentNetwork = Testnet3;
 
+    #[test]
+    fn test_register_access() -> Result<()> {
+        // Register::Locator
+        let register = Register::<CurrentNetwork>::from_str("r0")?;
+        assert!(!register.is_access());
+        assert_eq!(None, register.access_path());
+
+        // Register::Member
+        let register = Register::<CurrentNetwork>::from_str("r0.owner")?;
+        assert!(register.is_access());
+        assert_eq!(Some(&[Identifier::from_str("owner")?][..]), register.access_path());
+
+        let register = Register::<CurrentNetwork>::from_str("r0.a.b")?;
+        assert!(register.is_access());
+        assert_eq!(Some(&[Identifier::from_str("a")?, Identifier::from_str("b")?][..]), register.access_path());
+        Ok(())
+    }
+
     #[test]
     fn test_register_partial_ord() -> Result<()> {
         // Register::Locator
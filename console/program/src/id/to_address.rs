@@ -23,3 +23,35 @@ impl<N: Network> ProgramID<N> {
         Ok(Address::new(group))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_account::PrivateKey;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: usize = 100;
+
+    #[test]
+    fn test_to_address() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Ensure the program address is deterministic.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("token.aleo")?;
+        let address = program_id.to_address()?;
+        assert_eq!(address, program_id.to_address()?);
+
+        // Ensure distinct programs have distinct addresses.
+        let other_program_id = ProgramID::<CurrentNetwork>::from_str("credits.aleo")?;
+        assert_ne!(address, other_program_id.to_address()?);
+
+        // Ensure the program address differs from user-derived addresses.
+        for _ in 0..ITERATIONS {
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+            assert_ne!(address, Address::try_from(&private_key)?);
+        }
+        Ok(())
+    }
+}
@@ -67,9 +67,7 @@ impl<N: Network> Request<N> {
         let tcm = N::hash_psd2(&[tvk])?;
 
         // Compute the function ID as `Hash(network_id, program_id, function_name)`.
-        let function_id = N::hash_bhp1024(
-            &(U16::<N>::new(N::ID), program_id.name(), program_id.network(), function_name).to_bits_le(),
-        )?;
+        let function_id = Self::domain_separator(&program_id, &function_name)?;
 
         // Construct the hash input as `(r * G, pk_sig, pr_sig, caller, [tvk, tcm, function ID, input IDs])`.
         let mut message = Vec::with_capacity(5 + 2 * inputs.len());
@@ -229,4 +227,10 @@ impl<N: Network> Request<N> {
             tcm,
         })
     }
+
+    /// Returns the domain separator for the signature message of a request, which is the function ID, where:
+    ///     function ID := Hash(network_id, program_id, function_name)
+    pub fn domain_separator(program_id: &ProgramID<N>, function_name: &Identifier<N>) -> Result<Field<N>> {
+        N::hash_bhp1024(&(U16::<N>::new(N::ID), program_id.name(), program_id.network(), function_name).to_bits_le())
+    }
 }
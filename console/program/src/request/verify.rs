@@ -60,10 +60,7 @@ impl<N: Network> Request<N> {
         let response = self.signature.response();
 
         // Compute the function ID as `Hash(network_id, program_id, function_name)`.
-        let function_id = match N::hash_bhp1024(
-            &(U16::<N>::new(N::ID), self.program_id.name(), self.program_id.network(), &self.function_name)
-                .to_bits_le(),
-        ) {
+        let function_id = match Self::domain_separator(&self.program_id, &self.function_name) {
             Ok(function_id) => function_id,
             Err(error) => {
                 eprintln!("Failed to construct the function ID: {error}");
@@ -268,4 +265,45 @@ mod tests {
             assert!(request.verify(&input_types));
         }
     }
+
+    #[test]
+    fn test_domain_separator() {
+        let rng = &mut TestRng::default();
+
+        // Sample a random private key.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+        // Construct a program ID and two function names.
+        let program_id = ProgramID::from_str("token.aleo").unwrap();
+        let function_name = Identifier::from_str("transfer").unwrap();
+        let other_function_name = Identifier::from_str("mint").unwrap();
+
+        // Ensure the domain separator is distinct per function.
+        let domain_separator = Request::domain_separator(&program_id, &function_name).unwrap();
+        let other_domain_separator = Request::domain_separator(&program_id, &other_function_name).unwrap();
+        assert_ne!(domain_separator, other_domain_separator);
+
+        // Compute the signed request.
+        let inputs = [Value::from_str("{ token_amount: 9876543210u128 }").unwrap()];
+        let input_types = [ValueType::from_str("amount.public").unwrap()];
+        let request =
+            Request::sign(&private_key, program_id, function_name, inputs.into_iter(), &input_types, rng).unwrap();
+        assert!(request.verify(&input_types));
+
+        // Ensure the same request fails to verify under the domain separator of another function.
+        let relabeled = Request::from((
+            *request.caller(),
+            *request.network_id(),
+            *request.program_id(),
+            other_function_name,
+            request.input_ids().to_vec(),
+            request.inputs().to_vec(),
+            *request.signature(),
+            *request.sk_tag(),
+            *request.tvk(),
+            *request.tsk(),
+            *request.tcm(),
+        ));
+        assert!(!relabeled.verify(&input_types));
+    }
 }
@@ -0,0 +1,195 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The global state path proves existence of the transaction ID to a global state root.
+#[derive(Clone, PartialEq, Eq)]
+pub struct GlobalStatePath<N: Network> {
+    /// The global state root (Public).
+    global_state_root: N::StateRoot,
+    /// The Merkle path for the block hash.
+    block_path: BlockPath<N>,
+    /// The block hash.
+    block_hash: N::BlockHash,
+    /// The previous block hash.
+    previous_block_hash: N::BlockHash,
+    /// The block header root.
+    header_root: Field<N>,
+    /// The Merkle path for the block header leaf.
+    header_path: HeaderPath<N>,
+    /// The block header leaf.
+    header_leaf: HeaderLeaf<N>,
+    /// The Merkle path for the transaction ID.
+    transactions_path: TransactionsPath<N>,
+    /// The transaction ID.
+    transaction_id: N::TransactionID,
+}
+
+impl<N: Network> GlobalStatePath<N> {
+    /// Initializes a new instance of `GlobalStatePath`.
+    #[allow(clippy::too_many_arguments)]
+    pub const fn from(
+        global_state_root: N::StateRoot,
+        block_path: BlockPath<N>,
+        block_hash: N::BlockHash,
+        previous_block_hash: N::BlockHash,
+        header_root: Field<N>,
+        header_path: HeaderPath<N>,
+        header_leaf: HeaderLeaf<N>,
+        transactions_path: TransactionsPath<N>,
+        transaction_id: N::TransactionID,
+    ) -> Self {
+        Self {
+            global_state_root,
+            block_path,
+            block_hash,
+            previous_block_hash,
+            header_root,
+            header_path,
+            header_leaf,
+            transactions_path,
+            transaction_id,
+        }
+    }
+
+    /// Returns the global state root.
+    pub const fn global_state_root(&self) -> N::StateRoot {
+        self.global_state_root
+    }
+
+    /// Returns the block path.
+    pub const fn block_path(&self) -> &BlockPath<N> {
+        &self.block_path
+    }
+
+    /// Returns the block hash.
+    pub const fn block_hash(&self) -> N::BlockHash {
+        self.block_hash
+    }
+
+    /// Returns the previous block hash.
+    pub const fn previous_block_hash(&self) -> N::BlockHash {
+        self.previous_block_hash
+    }
+
+    /// Returns the block header root.
+    pub const fn header_root(&self) -> &Field<N> {
+        &self.header_root
+    }
+
+    /// Returns the header path.
+    pub const fn header_path(&self) -> &HeaderPath<N> {
+        &self.header_path
+    }
+
+    /// Returns the header leaf.
+    pub const fn header_leaf(&self) -> &HeaderLeaf<N> {
+        &self.header_leaf
+    }
+
+    /// Returns the transactions path.
+    pub const fn transactions_path(&self) -> &TransactionsPath<N> {
+        &self.transactions_path
+    }
+
+    /// Returns the transaction ID.
+    pub const fn transaction_id(&self) -> &N::TransactionID {
+        &self.transaction_id
+    }
+
+    /// Checks if the transaction ID belongs to the global state root.
+    ///
+    /// # Diagram
+    /// The `[[ ]]` notation is used to denote public inputs.
+    /// ```ignore
+    ///  [[ global_state_root ]]
+    ///           |
+    ///      block_path
+    ///          |
+    ///     block_hash := Hash( previous_block_hash || header_root )
+    ///                                                     |
+    ///                                                header_path
+    ///                                                    |
+    ///                                               header_leaf
+    ///                                                   |
+    ///                                            transactions_path
+    ///                                                  |
+    ///                                           transaction_id
+    /// ```
+    pub fn verify(&self) -> Result<()> {
+        Self::verify_parts(
+            &self.global_state_root,
+            &self.block_path,
+            &self.block_hash,
+            &self.previous_block_hash,
+            &self.header_root,
+            &self.header_path,
+            &self.header_leaf,
+            &self.transactions_path,
+            &self.transaction_id,
+        )
+    }
+
+    /// Checks if the given transaction ID belongs to the given global state root, by reference.
+    #[allow(clippy::too_many_arguments)]
+    pub(super) fn verify_parts(
+        global_state_root: &N::StateRoot,
+        block_path: &BlockPath<N>,
+        block_hash: &N::BlockHash,
+        previous_block_hash: &N::BlockHash,
+        header_root: &Field<N>,
+        header_path: &HeaderPath<N>,
+        header_leaf: &HeaderLeaf<N>,
+        transactions_path: &TransactionsPath<N>,
+        transaction_id: &N::TransactionID,
+    ) -> Result<()> {
+        // Ensure the header leaf index is 1 (Header::transactions_root).
+        ensure!(header_leaf.index() == 1, "Header leaf index must be 1 (Header::transactions_root)");
+        // Ensure the transactions path is valid.
+        ensure!(
+            N::verify_merkle_path_bhp(
+                transactions_path,
+                &header_leaf.id(),
+                &transaction_id.to_bits_le()
+            ),
+            "Transaction '{}' does not belong to '{}' (a header leaf)",
+            transaction_id,
+            header_leaf
+        );
+        // Ensure the header path is valid.
+        ensure!(
+            N::verify_merkle_path_bhp(header_path, header_root, &header_leaf.to_bits_le()),
+            "'{}' (a header leaf) does not belong to '{}' (a block header)",
+            header_leaf,
+            block_hash
+        );
+        // Ensure the block hash is correct.
+        let preimage = (**previous_block_hash).to_bits_le().into_iter().chain(header_root.to_bits_le());
+        ensure!(
+            **block_hash == N::hash_bhp1024(&preimage.collect::<Vec<_>>())?,
+            "Block hash '{}' is incorrect. Double-check the previous block hash and block header root.",
+            block_hash
+        );
+        // Ensure the global state root is correct.
+        ensure!(
+            N::verify_merkle_path_bhp(block_path, global_state_root, &block_hash.to_bits_le()),
+            "'{}' (a block hash) does not belong to '{}' (a global state root)",
+            block_hash,
+            global_state_root
+        );
+
+        Ok(())
+    }
+}
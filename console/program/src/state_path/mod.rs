@@ -15,12 +15,18 @@
 mod configuration;
 pub use configuration::*;
 
+mod global_state_path;
+pub use global_state_path::*;
+
 mod header_leaf;
 pub use header_leaf::*;
 
 mod transaction_leaf;
 pub use transaction_leaf::*;
 
+mod transaction_state_path;
+pub use transaction_state_path::*;
+
 pub mod transition_leaf;
 pub use transition_leaf::*;
 
@@ -207,6 +213,32 @@ impl<N: Network> StatePath<N> {
     pub const fn transition_leaf(&self) -> &TransitionLeaf<N> {
         &self.transition_leaf
     }
+
+    /// Returns the portion of the state path from the transition leaf to the transaction ID.
+    pub fn to_transaction_path(&self) -> TransactionStatePath<N> {
+        TransactionStatePath::from(
+            self.transaction_id,
+            self.transaction_path.clone(),
+            self.transaction_leaf,
+            self.transition_path.clone(),
+            self.transition_leaf,
+        )
+    }
+
+    /// Returns the portion of the state path from the transaction ID to the global state root.
+    pub fn to_global_path(&self) -> GlobalStatePath<N> {
+        GlobalStatePath::from(
+            self.global_state_root,
+            self.block_path.clone(),
+            self.block_hash,
+            self.previous_block_hash,
+            self.header_root,
+            self.header_path.clone(),
+            self.header_leaf,
+            self.transactions_path.clone(),
+            self.transaction_id,
+        )
+    }
 }
 
 #[cfg(any(test, feature = "test"))]
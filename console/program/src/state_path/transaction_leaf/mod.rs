@@ -52,6 +52,16 @@ impl<N: Network> TransactionLeaf<N> {
         Self { variant, index, id }
     }
 
+    /// Initializes a new instance of `TransactionLeaf` for the given transition in an execution.
+    pub fn from_execution(index: u16, transition_id: N::TransitionID) -> Self {
+        Self::new_execution(index, *transition_id)
+    }
+
+    /// Initializes a new instance of `TransactionLeaf` for the given program ID hash in a deployment.
+    pub const fn from_deployment(index: u16, program_id_hash: Field<N>) -> Self {
+        Self::new_deployment(index, program_id_hash)
+    }
+
     /// Returns the variant of the Merkle leaf.
     pub const fn variant(&self) -> u8 {
         self.variant
@@ -80,3 +90,34 @@ mod test_helpers {
         TransactionLeaf::from(rng.gen(), rng.gen(), Uniform::rand(rng))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_from_execution_and_deployment() {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let index = rng.gen();
+            let id: Field<CurrentNetwork> = Uniform::rand(rng);
+            let transition_id = <CurrentNetwork as Network>::TransitionID::from(id);
+
+            // Ensure `from_execution` matches `new_execution`.
+            let execution = TransactionLeaf::<CurrentNetwork>::from_execution(index, transition_id);
+            assert_eq!(execution.to_bits_le(), TransactionLeaf::new_execution(index, id).to_bits_le());
+
+            // Ensure `from_deployment` matches `new_deployment`, with a distinct variant.
+            let deployment = TransactionLeaf::<CurrentNetwork>::from_deployment(index, id);
+            assert_eq!(deployment.to_bits_le(), TransactionLeaf::new_deployment(index, id).to_bits_le());
+            assert_ne!(deployment.variant(), execution.variant());
+            assert_ne!(deployment.to_bits_le(), execution.to_bits_le());
+        }
+    }
+}
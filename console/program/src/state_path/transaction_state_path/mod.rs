@@ -0,0 +1,131 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The transaction state path proves existence of the transition leaf to a transaction ID.
+#[derive(Clone, PartialEq, Eq)]
+pub struct TransactionStatePath<N: Network> {
+    /// The transaction ID.
+    transaction_id: N::TransactionID,
+    /// The Merkle path for the transaction leaf.
+    transaction_path: TransactionPath<N>,
+    /// The transaction leaf.
+    transaction_leaf: TransactionLeaf<N>,
+    /// The Merkle path for the transition leaf.
+    transition_path: TransitionPath<N>,
+    /// The transition leaf.
+    transition_leaf: TransitionLeaf<N>,
+}
+
+impl<N: Network> TransactionStatePath<N> {
+    /// Initializes a new instance of `TransactionStatePath`.
+    pub const fn from(
+        transaction_id: N::TransactionID,
+        transaction_path: TransactionPath<N>,
+        transaction_leaf: TransactionLeaf<N>,
+        transition_path: TransitionPath<N>,
+        transition_leaf: TransitionLeaf<N>,
+    ) -> Self {
+        Self { transaction_id, transaction_path, transaction_leaf, transition_path, transition_leaf }
+    }
+
+    /// Returns the transaction ID.
+    pub const fn transaction_id(&self) -> &N::TransactionID {
+        &self.transaction_id
+    }
+
+    /// Returns the Merkle path for the transaction leaf.
+    pub const fn transaction_path(&self) -> &TransactionPath<N> {
+        &self.transaction_path
+    }
+
+    /// Returns the transaction leaf.
+    pub const fn transaction_leaf(&self) -> &TransactionLeaf<N> {
+        &self.transaction_leaf
+    }
+
+    /// Returns the Merkle path for the transition leaf.
+    pub const fn transition_path(&self) -> &TransitionPath<N> {
+        &self.transition_path
+    }
+
+    /// Returns the transition leaf.
+    pub const fn transition_leaf(&self) -> &TransitionLeaf<N> {
+        &self.transition_leaf
+    }
+
+    /// Checks if the transition leaf belongs to the transaction ID.
+    ///
+    /// # Diagram
+    /// ```ignore
+    ///  transaction_id
+    ///        |
+    ///  transaction_path
+    ///        |
+    ///  transaction_leaf
+    ///        |
+    ///  transition_path
+    ///        |
+    ///  transition_leaf
+    /// ```
+    pub fn verify(&self) -> Result<()> {
+        Self::verify_parts(
+            &self.transaction_id,
+            &self.transaction_path,
+            &self.transaction_leaf,
+            &self.transition_path,
+            &self.transition_leaf,
+        )
+    }
+
+    /// Checks if the given transition leaf belongs to the given transaction ID, by reference.
+    pub(super) fn verify_parts(
+        transaction_id: &N::TransactionID,
+        transaction_path: &TransactionPath<N>,
+        transaction_leaf: &TransactionLeaf<N>,
+        transition_path: &TransitionPath<N>,
+        transition_leaf: &TransitionLeaf<N>,
+    ) -> Result<()> {
+        // Ensure the transition leaf variant is 3 (Input::Record).
+        ensure!(transition_leaf.variant() == 3, "Transition leaf variant must be 3 (Input::Record)");
+        // Ensure the transition path is valid.
+        ensure!(
+            N::verify_merkle_path_bhp(
+                transition_path,
+                &transaction_leaf.id(),
+                &transition_leaf.to_bits_le()
+            ),
+            "'{}' (an input or output ID) does not belong to '{}' (a function or transition)",
+            transition_leaf.id(),
+            transaction_leaf.id()
+        );
+
+        // Ensure the transaction leaf variant is 1 (Transaction::Execution).
+        ensure!(transaction_leaf.variant() == 1, "Transaction leaf variant must be 1 (Transaction::Execution)");
+        // Ensure the transaction path is valid.
+        ensure!(
+            N::verify_merkle_path_bhp(
+                transaction_path,
+                transaction_id,
+                &transaction_leaf.to_bits_le()
+            ),
+            "'{}' (a function or transition) does not belong to transaction '{}'",
+            transaction_leaf.id(),
+            transaction_id
+        );
+
+        Ok(())
+    }
+}
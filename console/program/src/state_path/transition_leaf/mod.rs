@@ -46,6 +46,13 @@ impl<N: Network> TransitionLeaf<N> {
         Self { version, index, variant, id }
     }
 
+    /// Initializes a new instance of `TransitionLeaf` for a record output, from its commitment.
+    /// Note: The `index` is the position of the leaf in the transition tree (i.e. after the inputs).
+    pub const fn from_output_commitment(index: u8, commitment: Field<N>) -> Self {
+        // Note: The variant for a record output is `3`.
+        Self::new_with_version(index, 3, commitment)
+    }
+
     /// Returns the version of the Merkle leaf.
     pub const fn version(&self) -> u8 {
         self.version
@@ -65,6 +72,15 @@ impl<N: Network> TransitionLeaf<N> {
     pub const fn id(&self) -> Field<N> {
         self.id
     }
+
+    /// Returns the commitment to the Merkle leaf, which is the leaf hash in the transition tree.
+    pub fn to_commitment(&self) -> Result<Field<N>> {
+        // Prepend the leaf with a `false` bit, as done by the leaf hasher.
+        let mut input = vec![false];
+        input.extend(self.to_bits_le());
+        // Hash the input.
+        N::hash_bhp1024(&input)
+    }
 }
 
 #[cfg(test)]
@@ -79,3 +95,54 @@ mod test_helpers {
         TransitionLeaf::new_with_version(rng.gen(), rng.gen(), Uniform::rand(rng))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TransitionTree;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_to_commitment() {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample the leaves.
+            let leaves = (0..4).map(|_| test_helpers::sample_leaf(rng)).collect::<Vec<_>>();
+            // Construct the transition tree.
+            let tree: TransitionTree<CurrentNetwork> =
+                CurrentNetwork::merkle_tree_bhp(&leaves.iter().map(|leaf| leaf.to_bits_le()).collect::<Vec<_>>())
+                    .unwrap();
+
+            // Ensure the commitments match the leaf hashes in the tree.
+            let leaf_hashes = tree.leaf_hashes().unwrap();
+            for (leaf, leaf_hash) in leaves.iter().zip_eq(leaf_hashes) {
+                assert_eq!(leaf.to_commitment().unwrap(), *leaf_hash);
+            }
+        }
+    }
+
+    #[test]
+    fn test_from_output_commitment() {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let index = rng.gen();
+            let commitment = Uniform::rand(rng);
+
+            // Construct the leaf from the output commitment.
+            let leaf = TransitionLeaf::<CurrentNetwork>::from_output_commitment(index, commitment);
+            // Construct the leaf manually.
+            let expected = TransitionLeaf::<CurrentNetwork>::from(VERSION, index, 3, commitment);
+
+            assert_eq!(leaf.to_bits_le(), expected.to_bits_le());
+            assert_eq!(leaf.index(), index);
+            assert_eq!(leaf.variant(), 3);
+            assert_eq!(leaf.id(), commitment);
+        }
+    }
+}
@@ -50,69 +50,28 @@ impl<N: Network> StatePath<N> {
     ///                                                    transition_leaf
     /// ```
     pub fn verify(&self, is_global: bool, local_state_root: Field<N>) -> Result<()> {
-        // Ensure the transition leaf variant is 3 (Input::Record).
-        ensure!(self.transition_leaf.variant() == 3, "Transition leaf variant must be 3 (Input::Record)");
-        // Ensure the transition path is valid.
-        ensure!(
-            N::verify_merkle_path_bhp(
-                &self.transition_path,
-                &self.transaction_leaf.id(),
-                &self.transition_leaf.to_bits_le()
-            ),
-            "'{}' (an input or output ID) does not belong to '{}' (a function or transition)",
-            self.transition_leaf.id(),
-            self.transaction_leaf.id()
-        );
-
-        // Ensure the transaction leaf variant is 1 (Transaction::Execution).
-        ensure!(self.transaction_leaf.variant() == 1, "Transaction leaf variant must be 1 (Transaction::Execution)");
-        // Ensure the transaction path is valid.
-        ensure!(
-            N::verify_merkle_path_bhp(
-                &self.transaction_path,
-                &self.transaction_id,
-                &self.transaction_leaf.to_bits_le()
-            ),
-            "'{}' (a function or transition) does not belong to transaction '{}'",
-            self.transaction_leaf.id(),
-            self.transaction_id
-        );
+        // Ensure the transition leaf belongs to the transaction ID.
+        TransactionStatePath::verify_parts(
+            &self.transaction_id,
+            &self.transaction_path,
+            &self.transaction_leaf,
+            &self.transition_path,
+            &self.transition_leaf,
+        )?;
 
         if is_global {
-            // Ensure the header leaf index is 1 (Header::transactions_root).
-            ensure!(self.header_leaf.index() == 1, "Header leaf index must be 1 (Header::transactions_root)");
-            // Ensure the transactions path is valid.
-            ensure!(
-                N::verify_merkle_path_bhp(
-                    &self.transactions_path,
-                    &self.header_leaf.id(),
-                    &self.transaction_id.to_bits_le()
-                ),
-                "Transaction '{}' does not belong to '{}' (a header leaf)",
-                self.transaction_id,
-                self.header_leaf
-            );
-            // Ensure the header path is valid.
-            ensure!(
-                N::verify_merkle_path_bhp(&self.header_path, &self.header_root, &self.header_leaf.to_bits_le()),
-                "'{}' (a header leaf) does not belong to '{}' (a block header)",
-                self.header_leaf,
-                self.block_hash
-            );
-            // Ensure the block hash is correct.
-            let preimage = (*self.previous_block_hash).to_bits_le().into_iter().chain(self.header_root.to_bits_le());
-            ensure!(
-                *self.block_hash == N::hash_bhp1024(&preimage.collect::<Vec<_>>())?,
-                "Block hash '{}' is incorrect. Double-check the previous block hash and block header root.",
-                self.block_hash
-            );
-            // Ensure the global state root is correct.
-            ensure!(
-                N::verify_merkle_path_bhp(&self.block_path, &self.global_state_root, &self.block_hash.to_bits_le()),
-                "'{}' (a block hash) does not belong to '{}' (a global state root)",
-                self.block_hash,
-                self.global_state_root
-            );
+            // Ensure the transaction ID belongs to the global state root.
+            GlobalStatePath::verify_parts(
+                &self.global_state_root,
+                &self.block_path,
+                &self.block_hash,
+                &self.previous_block_hash,
+                &self.header_root,
+                &self.header_path,
+                &self.header_leaf,
+                &self.transactions_path,
+                &self.transaction_id,
+            )?;
         } else {
             // Ensure the local state root is correct.
             ensure!(
@@ -208,4 +167,36 @@ mod tests {
             new_local_state_path.verify(true, Field::rand(rng)).unwrap_err();
         }
     }
+
+    #[test]
+    fn test_verify_sub_paths() {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample the state paths.
+            let global_state_path =
+                crate::state_path::test_helpers::sample_global_state_path::<CurrentNetwork>(None, rng).unwrap();
+            let local_state_path =
+                crate::state_path::test_helpers::sample_local_state_path::<CurrentNetwork>(None, rng).unwrap();
+
+            for state_path in [global_state_path, local_state_path] {
+                // Split the state path.
+                let transaction_path = state_path.to_transaction_path();
+                let global_path = state_path.to_global_path();
+
+                // Ensure the sub-paths meet at the intermediate root.
+                assert_eq!(transaction_path.transaction_id(), global_path.transaction_id());
+                assert_eq!(transaction_path.transaction_id(), state_path.transaction_id());
+
+                // Ensure the sub-paths verify in the same way as the combined path.
+                let local_state_root = **transaction_path.transaction_id();
+                assert!(transaction_path.verify().is_ok());
+                assert_eq!(
+                    transaction_path.verify().is_ok() && global_path.verify().is_ok(),
+                    state_path.verify(true, local_state_root).is_ok()
+                );
+                assert_eq!(transaction_path.verify().is_ok(), state_path.verify(false, local_state_root).is_ok());
+            }
+        }
+    }
 }
@@ -0,0 +1,128 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The mask for the sign bit, which is stored in the most significant bit of the last byte.
+const SIGN_BIT_MASK: u8 = 0b1000_0000;
+
+impl<E: Environment> Group<E> {
+    /// Returns the compressed byte representation of the group, which is the little-endian *x-coordinate*,
+    /// with the most significant bit set if the *y-coordinate* is the lexicographically largest of the two candidates.
+    pub fn to_compressed_bytes(&self) -> [u8; 32] {
+        // Ensure the x-coordinate leaves the most significant bit free for the sign bit.
+        debug_assert!(Field::<E>::size_in_bits() < 256);
+
+        let affine = self.group.to_affine();
+        let (x_coordinate, y_coordinate) = (affine.to_x_coordinate(), affine.to_y_coordinate());
+
+        let mut bytes = [0u8; 32];
+        // Note: This call is infallible, as the x-coordinate is exactly 32 bytes.
+        x_coordinate.write_le(&mut bytes[..]).expect("Failed to write the x-coordinate of a group element");
+        // Set the sign bit, if the y-coordinate is the greatest.
+        if y_coordinate > -y_coordinate {
+            bytes[31] |= SIGN_BIT_MASK;
+        }
+        bytes
+    }
+
+    /// Attempts to recover a group from its compressed byte representation.
+    /// For safety, the resulting point is always enforced to be on the curve and in the correct subgroup.
+    pub fn from_compressed_bytes(bytes: &[u8; 32]) -> Result<Self> {
+        // Extract the sign bit, and clear it from the x-coordinate.
+        let mut x_bytes = *bytes;
+        let greatest = x_bytes[31] & SIGN_BIT_MASK != 0;
+        x_bytes[31] &= !SIGN_BIT_MASK;
+
+        // Recover the x-coordinate.
+        let x_coordinate = Field::<E>::read_le(&x_bytes[..])?;
+
+        // Recover the point, and ensure it is in the correct subgroup.
+        match E::Affine::from_x_coordinate(*x_coordinate, greatest) {
+            Some(point) if point.is_in_correct_subgroup_assuming_on_curve() => Ok(Self::new(point)),
+            _ => bail!("Failed to recover a group from the compressed bytes of x-coordinate {x_coordinate}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network_environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: u64 = 10_000;
+
+    #[test]
+    fn test_compressed_bytes() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a new group.
+            let expected = Group::<CurrentEnvironment>::new(Uniform::rand(&mut rng));
+
+            // Check the compressed byte representation.
+            let compressed = expected.to_compressed_bytes();
+            assert_eq!(expected, Group::from_compressed_bytes(&compressed)?);
+
+            // Check that the lower bytes match the x-coordinate.
+            let mut x_bytes = compressed;
+            x_bytes[31] &= !SIGN_BIT_MASK;
+            assert_eq!(expected.to_x_coordinate().to_bytes_le()?, x_bytes.to_vec());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_compressed_bytes_sign_bit() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a new group.
+            let expected = Group::<CurrentEnvironment>::new(Uniform::rand(&mut rng));
+
+            // Flip the sign bit, which is rejected by the subgroup check.
+            let mut compressed = expected.to_compressed_bytes();
+            compressed[31] ^= SIGN_BIT_MASK;
+            assert!(Group::<CurrentEnvironment>::from_compressed_bytes(&compressed).is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_compressed_bytes_invalid() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        let mut num_invalid = 0;
+        for _ in 0..ITERATIONS {
+            // Sample a random x-coordinate.
+            let x_coordinate = Field::<CurrentEnvironment>::rand(&mut rng);
+            // Skip x-coordinates that correspond to a valid group element.
+            if Group::<CurrentEnvironment>::from_x_coordinate(x_coordinate).is_ok() {
+                continue;
+            }
+            num_invalid += 1;
+
+            // Check that the invalid x-coordinate fails to decompress, regardless of the sign bit.
+            let mut compressed = [0u8; 32];
+            x_coordinate.write_le(&mut compressed[..])?;
+            assert!(Group::<CurrentEnvironment>::from_compressed_bytes(&compressed).is_err());
+            compressed[31] |= SIGN_BIT_MASK;
+            assert!(Group::<CurrentEnvironment>::from_compressed_bytes(&compressed).is_err());
+        }
+        assert!(num_invalid > 0);
+        Ok(())
+    }
+}
@@ -18,6 +18,7 @@
 mod arithmetic;
 mod bitwise;
 mod bytes;
+mod compressed_bytes;
 mod from_bits;
 mod from_field;
 mod from_fields;
@@ -91,6 +92,11 @@ impl<E: Environment> Group<E> {
     pub fn div_by_cofactor(&self) -> Self {
         Self { group: self.group.to_affine().mul_by_cofactor_inv().into() }
     }
+
+    /// Returns `true` if `self` is in the prime-order subgroup.
+    pub fn is_in_prime_subgroup(&self) -> bool {
+        self.group.to_affine().is_in_correct_subgroup_assuming_on_curve()
+    }
 }
 
 impl<E: Environment> Group<E> {
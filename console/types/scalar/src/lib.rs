@@ -30,6 +30,7 @@ mod size_in_bits;
 mod size_in_bytes;
 mod to_bits;
 mod to_field;
+mod to_radix_2_windows;
 mod zero;
 
 pub use snarkvm_console_network_environment::prelude::*;
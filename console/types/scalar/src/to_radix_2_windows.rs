@@ -0,0 +1,114 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> Scalar<E> {
+    /// Returns the signed radix-2^`window_size` representation of the scalar, in little-endian order.
+    ///
+    /// Each window is in the range `[-2^(window_size - 1), 2^(window_size - 1))`, such that
+    /// `self = sum_i windows[i] * 2^(window_size * i)`. The window size must be between 1 and 8.
+    pub fn to_radix_2_windows(&self, window_size: usize) -> Vec<i8> {
+        assert!((1..=8).contains(&window_size), "The window size must be between 1 and 8, found {window_size}");
+
+        // Note: The windows are computed in `i16` to accommodate the carry for a window size of 8.
+        let radix = 1i16 << window_size;
+        let half_radix = radix >> 1;
+
+        let bits_le = self.to_bits_le();
+        let mut windows = Vec::with_capacity(bits_le.len() / window_size + 2);
+        let mut carry = 0i16;
+        for chunk in bits_le.chunks(window_size) {
+            // Compute the unsigned value of the window, including the carry from the previous window.
+            let value = chunk.iter().rev().fold(0i16, |acc, bit| (acc << 1) | *bit as i16) + carry;
+            // Shift the window into the signed range, carrying into the next window as needed.
+            carry = (value >= half_radix) as i16;
+            let window = value - carry * radix;
+            // Note: This cast is safe, as the window is in the range `[-2^(window_size - 1), 2^(window_size - 1))`.
+            #[allow(clippy::cast_possible_truncation)]
+            windows.push(window as i8);
+        }
+        // Append the final carry, if one remains.
+        if carry != 0 {
+            windows.push(1);
+        }
+        windows
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network_environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: u64 = 1_000;
+
+    /// Reconstructs the scalar from the given windows using Horner's method.
+    fn reconstruct(windows: &[i8], window_size: usize) -> Scalar<CurrentEnvironment> {
+        let radix = Scalar::<CurrentEnvironment>::new((1u64 << window_size).into());
+        windows.iter().rev().fold(Scalar::zero(), |acc, window| {
+            let magnitude = Scalar::<CurrentEnvironment>::new((window.unsigned_abs() as u64).into());
+            match window.is_negative() {
+                true => acc * radix - magnitude,
+                false => acc * radix + magnitude,
+            }
+        })
+    }
+
+    fn check_to_radix_2_windows(window_size: usize) {
+        let mut rng = TestRng::default();
+
+        let half_radix = 1i16 << (window_size - 1);
+        for _ in 0..ITERATIONS {
+            // Sample a random scalar.
+            let expected: Scalar<CurrentEnvironment> = Uniform::rand(&mut rng);
+
+            // Compute the windows.
+            let candidate = expected.to_radix_2_windows(window_size);
+            for window in &candidate {
+                assert!((-half_radix..half_radix).contains(&(*window as i16)));
+            }
+            // Ensure the windows reconstruct the scalar.
+            assert_eq!(expected, reconstruct(&candidate, window_size));
+        }
+
+        // Check the edge cases.
+        for expected in [Scalar::<CurrentEnvironment>::zero(), Scalar::one(), -Scalar::<CurrentEnvironment>::one()] {
+            assert_eq!(expected, reconstruct(&expected.to_radix_2_windows(window_size), window_size));
+        }
+    }
+
+    #[test]
+    fn test_to_radix_2_windows_2() {
+        check_to_radix_2_windows(2);
+    }
+
+    #[test]
+    fn test_to_radix_2_windows_4() {
+        check_to_radix_2_windows(4);
+    }
+
+    #[test]
+    fn test_to_radix_2_windows_8() {
+        check_to_radix_2_windows(8);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_to_radix_2_windows_invalid_window_size() {
+        Scalar::<CurrentEnvironment>::one().to_radix_2_windows(9);
+    }
+}
@@ -17,8 +17,7 @@
 #[macro_use]
 extern crate tracing;
 
-mod helpers;
-pub use helpers::*;
+pub use synthesizer::block::{anchor_block_height, coinbase_reward, coinbase_target, proof_target, staking_reward};
 
 mod advance;
 mod check;
@@ -103,9 +103,16 @@ pub fn sample_assignment<N: Network, A: Aleo<Network = N>>() -> Result<(Assignme
     let serial_number = Record::<N, Plaintext<N>>::serial_number_from_gamma(&gamma, *commitment)?;
 
     // Construct the assignment for the inclusion circuit.
-    let assignment =
-        InclusionAssignment::new(state_path.clone(), *commitment, gamma, serial_number, Default::default(), true)
-            .to_circuit_assignment::<A>()?;
+    let assignment = InclusionAssignment::new(
+        Default::default(),
+        state_path.clone(),
+        *commitment,
+        gamma,
+        serial_number,
+        Default::default(),
+        true,
+    )
+    .to_circuit_assignment::<A>()?;
 
     Ok((assignment, state_path, serial_number))
 }
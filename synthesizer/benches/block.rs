@@ -16,7 +16,7 @@
 extern crate criterion;
 
 use console::{network::Testnet3, prelude::*};
-use snarkvm_synthesizer::Block;
+use snarkvm_synthesizer::{store::helpers::memory::ConsensusMemory, Block, ConsensusStore, VM};
 
 use criterion::Criterion;
 use serde::{de::DeserializeOwned, Serialize};
@@ -109,10 +109,20 @@ fn transition_serialization(c: &mut Criterion) {
     bench_serialization(c, "Transition", transition);
 }
 
+fn block_verification(c: &mut Criterion) {
+    let block = load_genesis_block();
+    let vm = VM::<CurrentNetwork, ConsensusMemory<_>>::from(ConsensusStore::open(None).unwrap()).unwrap();
+
+    c.bench_function("Block::verify_transactions (serial)", |b| {
+        b.iter(|| assert!(block.transactions().iter().all(|confirmed| vm.verify_transaction(confirmed, None))))
+    });
+    c.bench_function("Block::verify_transactions (parallel)", |b| b.iter(|| vm.verify_block_parallel(&block).unwrap()));
+}
+
 criterion_group! {
     name = block;
     config = Criterion::default().sample_size(10);
-    targets = block_serialization, block_header_serialization, block_transactions_serialization, transaction_serialization, transition_serialization
+    targets = block_serialization, block_header_serialization, block_transactions_serialization, transaction_serialization, transition_serialization, block_verification
 }
 
 criterion_main!(block);
@@ -59,9 +59,14 @@ impl<N: Network> PartialSolution<N> {
         CoinbasePuzzle::prover_polynomial(epoch_challenge, self.address(), self.nonce())
     }
 
+    /// Returns the proof-of-work hash of the solution.
+    pub fn to_proof_hash(&self) -> Result<u64> {
+        Ok(sha256d_to_u64(&self.commitment.to_bytes_le()?))
+    }
+
     /// Returns the target of the solution.
     pub fn to_target(&self) -> Result<u64> {
-        let hash_to_u64 = sha256d_to_u64(&self.commitment.to_bytes_le()?);
+        let hash_to_u64 = self.to_proof_hash()?;
         if hash_to_u64 == 0 { Ok(u64::MAX) } else { Ok(u64::MAX / hash_to_u64) }
     }
 }
@@ -46,7 +46,7 @@ impl<N: Network> ProverSolution<N> {
         }
 
         // Ensure that the prover solution is greater than the proof target.
-        if self.to_target()? < proof_target {
+        if !N::verify_proof_of_work(proof_target, self.to_proof_hash()?) {
             bail!("Prover puzzle does not meet the proof target requirements.")
         }
 
@@ -95,6 +95,11 @@ impl<N: Network> ProverSolution<N> {
         self.partial_solution.to_prover_polynomial(epoch_challenge)
     }
 
+    /// Returns the proof-of-work hash of the solution.
+    pub fn to_proof_hash(&self) -> Result<u64> {
+        self.partial_solution.to_proof_hash()
+    }
+
     /// Returns the target of the solution.
     pub fn to_target(&self) -> Result<u64> {
         self.partial_solution.to_target()
@@ -296,7 +296,7 @@ impl<N: Network> CoinbasePuzzle<N> {
         // Compute the prover polynomials.
         let prover_polynomials = cfg_iter!(coinbase_solution.partial_solutions())
             // Ensure that each of the prover solutions meets the required proof target.
-            .map(|solution| match solution.to_target()? >= proof_target {
+            .map(|solution| match N::verify_proof_of_work(proof_target, solution.to_proof_hash()?) {
                 // Compute the prover polynomial.
                 true => solution.to_prover_polynomial(epoch_challenge),
                 false => bail!("Prover puzzle does not meet the proof target requirements."),
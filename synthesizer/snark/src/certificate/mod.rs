@@ -30,6 +30,16 @@ impl<N: Network> Certificate<N> {
         Self { certificate }
     }
 
+    /// Returns an empty certificate, which does not certify any verifying key.
+    pub fn empty() -> Self {
+        Self::new(marlin::Certificate::new(sonic_pc::BatchLCProof { proof: Default::default(), evaluations: None }))
+    }
+
+    /// Returns `true` if the certificate is empty.
+    pub fn is_empty(&self) -> bool {
+        self.certificate.pc_proof.proof.is_empty() && self.certificate.pc_proof.evaluations.is_none()
+    }
+
     /// Returns the certificate from the proving and verifying key.
     pub fn certify(
         function_name: &str,
@@ -52,6 +62,14 @@ impl<N: Network> Certificate<N> {
         Ok(Self::new(certificate))
     }
 
+    /// Returns `true` if the certificate is structurally well-formed, without invoking the proof verifier.
+    /// A certificate is well-formed if it has exactly the shape produced by `Marlin::prove_vk`, namely
+    /// a single opening proof (of the circuit check linear combination at the challenge point) and no evaluations.
+    /// Note: This check is necessary but not sufficient; the caller must still call `verify`.
+    pub fn is_well_formed(&self) -> bool {
+        self.certificate.pc_proof.proof.len() == 1 && self.certificate.pc_proof.evaluations.is_none()
+    }
+
     /// Returns the certificate from the proving and verifying key.
     pub fn verify(
         &self,
@@ -93,3 +111,27 @@ impl<N: Network> Deref for Certificate<N> {
         &self.certificate
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_is_well_formed() {
+        // Ensure a certified certificate is well-formed.
+        let certificate = crate::test_helpers::sample_certificate();
+        assert!(certificate.is_well_formed());
+
+        // Ensure an empty certificate is malformed.
+        assert!(!Certificate::<CurrentNetwork>::empty().is_well_formed());
+
+        // Ensure a certificate carrying evaluations is malformed.
+        let mut pc_proof = certificate.pc_proof.clone();
+        pc_proof.evaluations = Some(vec![]);
+        let malformed = Certificate::<CurrentNetwork>::new(marlin::Certificate::new(pc_proof));
+        assert!(!malformed.is_well_formed());
+    }
+}
@@ -15,6 +15,7 @@
 use super::*;
 
 mod bytes;
+mod file;
 mod parse;
 mod serialize;
 
@@ -18,6 +18,39 @@ impl<N: Network> Block<N> {
     /// Specifies the number of genesis transactions.
     pub const NUM_GENESIS_TRANSACTIONS: usize = 16;
 
+    /// Initializes a genesis block with the given parameters, for configurable test networks.
+    /// The `supply` is denominated in credits, and is converted to microcredits.
+    /// Note: The parameters are not checked against the genesis constants of the network `N`,
+    /// so `is_genesis` only holds for the resulting block if they match the network defaults.
+    pub fn genesis_with_params<R: Rng + CryptoRng>(
+        private_key: &PrivateKey<N>,
+        transactions: Transactions<N>,
+        supply: u64,
+        coinbase_target: u64,
+        proof_target: u64,
+        timestamp: i64,
+        rng: &mut R,
+    ) -> Result<Self> {
+        // Compute the total supply in microcredits.
+        let total_supply_in_microcredits = match supply.checked_mul(1_000_000) {
+            Some(total_supply_in_microcredits) => total_supply_in_microcredits,
+            None => bail!("The genesis supply of {supply} credits overflows"),
+        };
+        // Prepare the block header.
+        let header = Header::genesis_with_params(
+            &transactions,
+            total_supply_in_microcredits,
+            coinbase_target,
+            proof_target,
+            timestamp,
+        )?;
+        // Prepare the previous block hash.
+        let previous_hash = N::BlockHash::default();
+
+        // Construct the block, without a coinbase solution.
+        Self::new(private_key, previous_hash, header, transactions, None, rng)
+    }
+
     /// Returns `true` if the block is a genesis block.
     pub fn is_genesis(&self) -> bool {
         // Ensure the previous block hash is zero.
@@ -55,4 +88,58 @@ mod tests {
         // println!("{}", serde_json::to_string_pretty(&block).unwrap());
         assert!(new_genesis_block.is_genesis());
     }
+
+    #[test]
+    fn test_genesis_with_params() {
+        let mut rng = TestRng::default();
+
+        // Load the genesis transactions.
+        let transactions =
+            Block::<CurrentNetwork>::read_le(CurrentNetwork::genesis_bytes()).unwrap().transactions().clone();
+        // Sample a caller.
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng).unwrap();
+
+        // Create a genesis block with the network defaults.
+        let block = Block::genesis_with_params(
+            &private_key,
+            transactions.clone(),
+            CurrentNetwork::STARTING_SUPPLY / 1_000_000,
+            CurrentNetwork::GENESIS_COINBASE_TARGET,
+            CurrentNetwork::GENESIS_PROOF_TARGET,
+            CurrentNetwork::GENESIS_TIMESTAMP,
+            &mut rng,
+        )
+        .unwrap();
+        assert!(block.is_genesis());
+        assert_eq!(block.header().total_supply_in_microcredits(), CurrentNetwork::STARTING_SUPPLY);
+        assert_eq!(block.height(), 0);
+        assert_eq!(block.previous_hash(), <CurrentNetwork as Network>::BlockHash::default());
+
+        // Ensure the block round-trips through bytes.
+        let bytes = block.to_bytes_le().unwrap();
+        assert_eq!(Block::<CurrentNetwork>::read_le(&bytes[..]).unwrap(), block);
+
+        // Create a genesis block with a custom supply.
+        let block = Block::genesis_with_params(
+            &private_key,
+            transactions.clone(),
+            1_000_000,
+            1 << 10,
+            1 << 5,
+            1_700_000_000,
+            &mut rng,
+        )
+        .unwrap();
+        assert_eq!(block.header().total_supply_in_microcredits(), 1_000_000 * 1_000_000);
+        assert_eq!(block.header().coinbase_target(), 1 << 10);
+        assert_eq!(block.header().proof_target(), 1 << 5);
+        assert_eq!(block.header().timestamp(), 1_700_000_000);
+        assert_eq!(block.height(), 0);
+        assert_eq!(block.previous_hash(), <CurrentNetwork as Network>::BlockHash::default());
+
+        // Ensure an overflowing supply fails.
+        assert!(
+            Block::genesis_with_params(&private_key, transactions, u64::MAX, 1 << 10, 1 << 5, 0, &mut rng).is_err()
+        );
+    }
 }
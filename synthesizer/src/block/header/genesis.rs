@@ -28,6 +28,31 @@ impl<N: Network> Header<N> {
         Self::from(previous_state_root, transactions_root, finalize_root, coinbase_accumulator_point, metadata)
     }
 
+    /// Initializes a genesis block header with the given parameters, for configurable test networks.
+    /// Note: The parameters are not checked against the genesis constants of the network `N`
+    /// (see `Metadata::genesis_with_params`).
+    pub fn genesis_with_params(
+        transactions: &Transactions<N>,
+        total_supply_in_microcredits: u64,
+        coinbase_target: u64,
+        proof_target: u64,
+        timestamp: i64,
+    ) -> Result<Self> {
+        // Prepare a genesis block header.
+        let previous_state_root = Field::zero();
+        let transactions_root = transactions.to_transactions_root()?;
+        let finalize_root = transactions.to_finalize_root()?;
+        let coinbase_accumulator_point = Field::zero();
+        let metadata =
+            Metadata::genesis_with_params(total_supply_in_microcredits, coinbase_target, proof_target, timestamp)?;
+
+        // Ensure the transactions root is nonzero.
+        ensure!(transactions_root != Field::zero(), "The genesis transactions root must be nonzero");
+
+        // Return the genesis block header.
+        Ok(Self { previous_state_root, transactions_root, finalize_root, coinbase_accumulator_point, metadata })
+    }
+
     /// Returns `true` if the block header is a genesis block header.
     pub fn is_genesis(&self) -> bool {
         // Ensure the previous ledger root is zero.
@@ -99,4 +124,39 @@ mod tests {
         // Ensure the genesis block does *not* contain the following.
         assert_ne!(header.transactions_root(), Field::zero());
     }
+
+    #[test]
+    fn test_genesis_with_params() {
+        // Load the genesis block.
+        let block = crate::Block::<CurrentNetwork>::read_le(CurrentNetwork::genesis_bytes()).unwrap();
+
+        // Prepare the genesis block header with the network defaults.
+        let header = Header::genesis_with_params(
+            block.transactions(),
+            CurrentNetwork::STARTING_SUPPLY,
+            CurrentNetwork::GENESIS_COINBASE_TARGET,
+            CurrentNetwork::GENESIS_PROOF_TARGET,
+            CurrentNetwork::GENESIS_TIMESTAMP,
+        )
+        .unwrap();
+        assert!(header.is_genesis());
+        assert_eq!(&header, block.header());
+
+        // Ensure the header round-trips through bytes.
+        let bytes = header.to_bytes_le().unwrap();
+        assert_eq!(Header::<CurrentNetwork>::read_le(&bytes[..]).unwrap(), header);
+
+        // Prepare a genesis block header with custom parameters.
+        let header =
+            Header::genesis_with_params(block.transactions(), 1_000_000, 1 << 10, 1 << 5, 1_700_000_000).unwrap();
+        assert_eq!(header.previous_state_root(), Field::zero());
+        assert_eq!(header.transactions_root(), block.header().transactions_root());
+        assert_eq!(header.height(), 0);
+        assert_eq!(header.total_supply_in_microcredits(), 1_000_000);
+        assert_eq!(header.coinbase_target(), 1 << 10);
+        assert_eq!(header.proof_target(), 1 << 5);
+        assert_eq!(header.timestamp(), 1_700_000_000);
+        // Ensure the header is not a genesis block header for the network, as the parameters differ from the defaults.
+        assert!(!header.is_genesis());
+    }
 }
@@ -44,6 +44,38 @@ impl<N: Network> Metadata<N> {
         )
     }
 
+    /// Initializes a genesis metadata with the given parameters, for configurable test networks.
+    /// Note: The parameters are not checked against the genesis constants of the network `N`, so the resulting
+    /// metadata only satisfies `is_genesis` (and may only be read back from bytes) if they match the network defaults.
+    pub fn genesis_with_params(
+        total_supply_in_microcredits: u64,
+        coinbase_target: u64,
+        proof_target: u64,
+        timestamp: i64,
+    ) -> Result<Self> {
+        // Ensure the total supply is nonzero.
+        ensure!(total_supply_in_microcredits != 0, "The genesis total supply must be nonzero");
+        // Ensure the proof target is nonzero.
+        ensure!(proof_target != 0, "The genesis proof target must be nonzero");
+        // Ensure the coinbase target is larger than the proof target.
+        ensure!(coinbase_target > proof_target, "The genesis coinbase target must be larger than the proof target");
+
+        // Return the genesis metadata.
+        Ok(Self {
+            network: N::ID,
+            round: 0,
+            height: 0,
+            total_supply_in_microcredits,
+            cumulative_weight: 0,
+            coinbase_target,
+            proof_target,
+            last_coinbase_target: coinbase_target,
+            last_coinbase_timestamp: timestamp,
+            timestamp,
+            _phantom: PhantomData,
+        })
+    }
+
     /// Returns `true` if the metadata is a genesis metadata.
     pub fn is_genesis(&self) -> bool {
         // Ensure the network ID is correct.
@@ -118,4 +150,43 @@ mod tests {
         assert_eq!(metadata.last_coinbase_timestamp(), CurrentNetwork::GENESIS_TIMESTAMP);
         assert_eq!(metadata.timestamp(), CurrentNetwork::GENESIS_TIMESTAMP);
     }
+
+    #[test]
+    fn test_genesis_with_params() {
+        // Ensure the network defaults produce a genesis metadata.
+        let metadata = Metadata::<CurrentNetwork>::genesis_with_params(
+            CurrentNetwork::STARTING_SUPPLY,
+            CurrentNetwork::GENESIS_COINBASE_TARGET,
+            CurrentNetwork::GENESIS_PROOF_TARGET,
+            CurrentNetwork::GENESIS_TIMESTAMP,
+        )
+        .unwrap();
+        assert!(metadata.is_genesis());
+        assert_eq!(Metadata::genesis().unwrap(), metadata);
+
+        // Ensure the metadata round-trips through bytes.
+        let bytes = metadata.to_bytes_le().unwrap();
+        assert_eq!(Metadata::<CurrentNetwork>::read_le(&bytes[..]).unwrap(), metadata);
+
+        // Prepare a genesis metadata with custom parameters.
+        let metadata =
+            Metadata::<CurrentNetwork>::genesis_with_params(1_000_000, 1 << 10, 1 << 5, 1_700_000_000).unwrap();
+        assert_eq!(metadata.network(), CurrentNetwork::ID);
+        assert_eq!(metadata.round(), 0);
+        assert_eq!(metadata.height(), 0);
+        assert_eq!(metadata.total_supply_in_microcredits(), 1_000_000);
+        assert_eq!(metadata.cumulative_weight(), 0);
+        assert_eq!(metadata.coinbase_target(), 1 << 10);
+        assert_eq!(metadata.proof_target(), 1 << 5);
+        assert_eq!(metadata.last_coinbase_target(), 1 << 10);
+        assert_eq!(metadata.last_coinbase_timestamp(), 1_700_000_000);
+        assert_eq!(metadata.timestamp(), 1_700_000_000);
+        // Ensure the metadata is not a genesis metadata for the network, as the parameters differ from the defaults.
+        assert!(!metadata.is_genesis());
+
+        // Ensure invalid parameters fail.
+        assert!(Metadata::<CurrentNetwork>::genesis_with_params(0, 1 << 10, 1 << 5, 0).is_err());
+        assert!(Metadata::<CurrentNetwork>::genesis_with_params(1, 1 << 10, 0, 0).is_err());
+        assert!(Metadata::<CurrentNetwork>::genesis_with_params(1, 1 << 5, 1 << 5, 0).is_err());
+    }
 }
@@ -19,6 +19,7 @@ mod string;
 mod to_bits;
 mod to_hash;
 
+use crate::block::{coinbase_target, proof_target};
 use console::{network::prelude::*, types::Field};
 
 use core::marker::PhantomData;
@@ -113,6 +114,87 @@ impl<N: Network> Metadata<N> {
             }
         }
     }
+
+    /// Checks that the given `next` metadata is a valid successor of this metadata,
+    /// where `actual_timestamp` is the current Unix timestamp (UTC) of the validator.
+    pub fn is_valid_next(&self, next: &Metadata<N>, actual_timestamp: i64) -> Result<()> {
+        // Ensure the next metadata is well-formed.
+        ensure!(next.is_valid(), "Invalid next block metadata: {next:?}");
+        // Ensure the network ID is unchanged.
+        ensure!(next.network == self.network, "The next block has an incorrect network ID");
+
+        // Ensure the next height is incremented.
+        ensure!(self.height.checked_add(1) == Some(next.height), "The next block has an incorrect block height");
+        // Ensure the next round is incremented.
+        ensure!(self.round.checked_add(1) == Some(next.round), "The next block has an incorrect round number");
+
+        // Ensure the next timestamp advances.
+        ensure!(
+            next.timestamp > self.timestamp,
+            "The next block timestamp {} is before the current timestamp {}",
+            next.timestamp,
+            self.timestamp
+        );
+        // Ensure the next timestamp is not in the future.
+        ensure!(
+            next.timestamp <= actual_timestamp,
+            "The next block timestamp {} is after the actual timestamp {actual_timestamp}",
+            next.timestamp
+        );
+
+        // Ensure the next coinbase target is retargeted correctly.
+        let expected_coinbase_target = coinbase_target(
+            self.last_coinbase_target,
+            self.last_coinbase_timestamp,
+            next.timestamp,
+            N::ANCHOR_TIME,
+            N::NUM_BLOCKS_PER_EPOCH,
+            N::GENESIS_COINBASE_TARGET,
+        )?;
+        ensure!(
+            next.coinbase_target == expected_coinbase_target,
+            "Invalid coinbase target: expected {expected_coinbase_target}, got {}",
+            next.coinbase_target
+        );
+        // Ensure the next proof target is retargeted correctly.
+        let expected_proof_target = proof_target(expected_coinbase_target, N::GENESIS_PROOF_TARGET);
+        ensure!(
+            next.proof_target == expected_proof_target,
+            "Invalid proof target: expected {expected_proof_target}, got {}",
+            next.proof_target
+        );
+
+        // Ensure the cumulative weight is updated, according to whether the next block contains a coinbase.
+        match next.last_coinbase_timestamp == next.timestamp {
+            // If the next block contains a coinbase, the cumulative weight must increase.
+            true => {
+                ensure!(
+                    next.last_coinbase_target == next.coinbase_target,
+                    "The last coinbase target does not match the coinbase target"
+                );
+                ensure!(
+                    next.cumulative_weight > self.cumulative_weight,
+                    "The cumulative weight does not include the block cumulative proof target"
+                );
+            }
+            // Otherwise, the last coinbase and cumulative weight must be unchanged.
+            false => {
+                ensure!(
+                    next.last_coinbase_target == self.last_coinbase_target,
+                    "The last coinbase target does not match the previous block coinbase target"
+                );
+                ensure!(
+                    next.last_coinbase_timestamp == self.last_coinbase_timestamp,
+                    "The last coinbase timestamp does not match the previous block's last coinbase timestamp"
+                );
+                ensure!(
+                    next.cumulative_weight == self.cumulative_weight,
+                    "The cumulative weight does not match the previous block's cumulative weight"
+                );
+            }
+        }
+        Ok(())
+    }
 }
 
 impl<N: Network> Metadata<N> {
@@ -166,3 +248,104 @@ impl<N: Network> Metadata<N> {
         self.timestamp
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Returns the next metadata for the given metadata, at the given timestamp.
+    fn sample_next(
+        metadata: &Metadata<CurrentNetwork>,
+        timestamp: i64,
+        block_cumulative_proof_target: Option<u128>,
+    ) -> Metadata<CurrentNetwork> {
+        // Compute the next targets.
+        let coinbase_target = coinbase_target(
+            metadata.last_coinbase_target,
+            metadata.last_coinbase_timestamp,
+            timestamp,
+            CurrentNetwork::ANCHOR_TIME,
+            CurrentNetwork::NUM_BLOCKS_PER_EPOCH,
+            CurrentNetwork::GENESIS_COINBASE_TARGET,
+        )
+        .unwrap();
+        let proof_target = proof_target(coinbase_target, CurrentNetwork::GENESIS_PROOF_TARGET);
+        // Compute the last coinbase members and the cumulative weight.
+        let (last_coinbase_target, last_coinbase_timestamp, cumulative_weight) = match block_cumulative_proof_target {
+            Some(target) => (coinbase_target, timestamp, metadata.cumulative_weight + target),
+            None => (metadata.last_coinbase_target, metadata.last_coinbase_timestamp, metadata.cumulative_weight),
+        };
+        Metadata::new(
+            CurrentNetwork::ID,
+            metadata.round + 1,
+            metadata.height + 1,
+            metadata.total_supply_in_microcredits,
+            cumulative_weight,
+            coinbase_target,
+            proof_target,
+            last_coinbase_target,
+            last_coinbase_timestamp,
+            timestamp,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_is_valid_next() {
+        let anchor_time = CurrentNetwork::ANCHOR_TIME as i64;
+
+        // Prepare a chain of metadata: genesis, a block with a coinbase, and a block without a coinbase.
+        let genesis = Metadata::<CurrentNetwork>::genesis().unwrap();
+        let first = sample_next(&genesis, CurrentNetwork::GENESIS_TIMESTAMP + anchor_time, Some(100));
+        let second = sample_next(&first, first.timestamp + anchor_time, None);
+        let now = second.timestamp + anchor_time;
+
+        // Ensure the chain is valid.
+        genesis.is_valid_next(&first, now).unwrap();
+        first.is_valid_next(&second, now).unwrap();
+
+        // Ensure a skipped or repeated block is invalid.
+        assert!(genesis.is_valid_next(&second, now).is_err());
+        assert!(first.is_valid_next(&first, now).is_err());
+
+        // Ensure an incorrect height is invalid.
+        assert!(first.is_valid_next(&Metadata { height: second.height + 1, ..second }, now).is_err());
+        // Ensure an incorrect round is invalid.
+        assert!(first.is_valid_next(&Metadata { round: second.round + 1, ..second }, now).is_err());
+        // Ensure a timestamp that does not advance is invalid.
+        let stale = sample_next(&first, first.timestamp, None);
+        assert!(first.is_valid_next(&stale, now).is_err());
+        // Ensure a timestamp in the future is invalid.
+        assert!(first.is_valid_next(&second, second.timestamp - 1).is_err());
+        first.is_valid_next(&second, second.timestamp).unwrap();
+
+        // Ensure an incorrect cumulative weight is invalid.
+        assert!(
+            first.is_valid_next(&Metadata { cumulative_weight: first.cumulative_weight + 1, ..second }, now).is_err()
+        );
+        assert!(
+            genesis.is_valid_next(&Metadata { cumulative_weight: genesis.cumulative_weight, ..first }, now).is_err()
+        );
+        // Ensure an incorrect last coinbase is invalid.
+        assert!(
+            first
+                .is_valid_next(&Metadata { last_coinbase_target: second.last_coinbase_target + 1, ..second }, now)
+                .is_err()
+        );
+        assert!(
+            first
+                .is_valid_next(&Metadata { last_coinbase_timestamp: second.last_coinbase_timestamp + 1, ..second }, now)
+                .is_err()
+        );
+
+        // Ensure an incorrect coinbase target is invalid.
+        assert!(first.is_valid_next(&Metadata { coinbase_target: second.coinbase_target + 1, ..second }, now).is_err());
+        // Ensure an incorrect proof target is invalid.
+        assert!(first.is_valid_next(&Metadata { proof_target: second.proof_target + 1, ..second }, now).is_err());
+        // Ensure an incorrect network ID is invalid.
+        assert!(first.is_valid_next(&Metadata { network: second.network + 1, ..second }, now).is_err());
+    }
+}
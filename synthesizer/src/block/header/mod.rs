@@ -77,6 +77,27 @@ impl<N: Network> Header<N> {
         }
     }
 
+    /// Checks that this block header is a valid successor of the given `previous` header,
+    /// where `previous_state_root` is the state root of the ledger up to (and including) the `previous` block.
+    /// Note: This does *not* check the timestamp against the current time of the validator.
+    pub fn is_valid_successor(&self, previous: &Header<N>, previous_state_root: Field<N>) -> Result<()> {
+        // Ensure the metadata is a valid successor.
+        previous.metadata.is_valid_next(&self.metadata, self.timestamp())?;
+        // Ensure the previous state root is correct.
+        ensure!(
+            self.previous_state_root == previous_state_root,
+            "The previous state root in the block header is incorrect (expected {previous_state_root}, found {})",
+            self.previous_state_root
+        );
+        // Ensure the coinbase accumulator point is nonzero if and only if the block contains a coinbase.
+        let has_coinbase = self.last_coinbase_timestamp() == self.timestamp();
+        ensure!(
+            (self.coinbase_accumulator_point != Field::zero()) == has_coinbase,
+            "The coinbase accumulator point in the block header does not match the last coinbase timestamp"
+        );
+        Ok(())
+    }
+
     /// Returns the previous state root from the block header.
     pub const fn previous_state_root(&self) -> Field<N> {
         self.previous_state_root
@@ -152,3 +173,38 @@ impl<N: Network> Header<N> {
         self.metadata.timestamp()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::block::Block;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_is_valid_successor() {
+        let rng = &mut TestRng::default();
+
+        // Load the genesis block.
+        let genesis = Block::<CurrentNetwork>::read_le(CurrentNetwork::genesis_bytes()).unwrap();
+        let previous = *genesis.header();
+
+        // Construct a successor header.
+        let metadata = genesis.next_block_params(genesis.timestamp() + 10, genesis.round() + 1).unwrap();
+        let state_root = Field::rand(rng);
+        let transactions_root = Field::rand(rng);
+        let header = |state_root, accumulator_point| {
+            Header::from(state_root, transactions_root, Field::zero(), accumulator_point, metadata).unwrap()
+        };
+
+        // Ensure a correctly-linked header passes.
+        header(state_root, Field::zero()).is_valid_successor(&previous, state_root).unwrap();
+        // Ensure a broken previous state root fails.
+        assert!(header(state_root, Field::zero()).is_valid_successor(&previous, Field::rand(rng)).is_err());
+        // Ensure a coinbase accumulator point without a coinbase fails.
+        assert!(header(state_root, Field::rand(rng)).is_valid_successor(&previous, state_root).is_err());
+        // Ensure the genesis header is not a successor of itself.
+        assert!(previous.is_valid_successor(&previous, state_root).is_err());
+    }
+}
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use super::*;
+use console::program::BlockTree;
 
 /// Calculate the staking reward, given the starting supply and anchor time.
 ///     R_staking = floor((0.025 * S) / H_Y1)
@@ -199,6 +200,32 @@ fn retarget(
     Ok(candidate_target as u64)
 }
 
+/// Computes the state root of the ledger containing the given block headers.
+/// The block headers must be given in order, starting from the genesis block.
+pub fn compute_state_root<N: Network>(block_headers: &[Header<N>]) -> Result<N::StateRoot> {
+    // Prepare the block hashes, starting from the genesis block.
+    let mut previous_hash = N::BlockHash::default();
+    let mut leaves = Vec::with_capacity(block_headers.len());
+    for (expected_height, header) in block_headers.iter().enumerate() {
+        // Ensure the block headers are in order.
+        ensure!(
+            header.height() as usize == expected_height,
+            "Expected block header at height {expected_height}, found height {}",
+            header.height()
+        );
+        // Compute the block hash.
+        let block_hash = N::hash_bhp1024(&[previous_hash.to_bits_le(), header.to_root()?.to_bits_le()].concat())?;
+        // Append the block hash to the leaves.
+        leaves.push(block_hash.to_bits_le());
+        // Update the previous block hash.
+        previous_hash = block_hash.into();
+    }
+    // Construct the block tree.
+    let tree: BlockTree<N> = N::merkle_tree_bhp(&leaves)?;
+    // Return the state root.
+    Ok((*tree.root()).into())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
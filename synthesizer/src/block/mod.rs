@@ -15,6 +15,9 @@
 mod header;
 pub use header::*;
 
+mod helpers;
+pub use helpers::*;
+
 mod transaction;
 pub use transaction::*;
 
@@ -32,7 +35,7 @@ mod string;
 use console::{
     account::{PrivateKey, Signature},
     network::prelude::*,
-    program::{Ciphertext, Record},
+    program::{Ciphertext, Record, TransactionsPath, FINALIZE_OPERATIONS_DEPTH, TRANSACTIONS_DEPTH},
     types::{Field, Group, U64},
 };
 use snarkvm_synthesizer_coinbase::{CoinbaseSolution, PuzzleCommitment};
@@ -105,6 +108,72 @@ impl<N: Network> Block<N> {
     }
 }
 
+impl<N: Network> Block<N> {
+    /// Returns the expected finalize root, by computing the root for a Merkle tree of the given finalize operations.
+    /// Note: The finalize root commits only to the operations of a single block, and is not chained to previous roots.
+    pub fn compute_expected_finalize_root(operations: &[FinalizeOperation<N>]) -> Result<Field<N>> {
+        // Prepare the leaves.
+        let leaves = operations.iter().map(ToBits::to_bits_le).collect::<Vec<_>>();
+        // Compute the finalize tree.
+        let tree = N::merkle_tree_bhp::<FINALIZE_OPERATIONS_DEPTH>(&leaves)?;
+        // Return the finalize root.
+        Ok(*tree.root())
+    }
+}
+
+impl<N: Network> Block<N> {
+    /// Returns the Merkle path for the given transaction ID, against the transactions root of the block.
+    pub fn to_transaction_inclusion_proof(&self, transaction_id: &N::TransactionID) -> Result<TransactionsPath<N>> {
+        self.transactions.to_path(*transaction_id)
+    }
+
+    /// Returns `true` if the given Merkle path proves the transaction ID is in the given transactions root.
+    pub fn verify_transaction_inclusion_proof(
+        transactions_root: &Field<N>,
+        transaction_id: &N::TransactionID,
+        proof: &TransactionsPath<N>,
+    ) -> bool {
+        N::verify_merkle_path_bhp::<TRANSACTIONS_DEPTH>(proof, transactions_root, &transaction_id.to_bits_le())
+    }
+}
+
+impl<N: Network> Block<N> {
+    /// Returns the expected metadata for the next block, given the proposed timestamp and round.
+    /// Note: This assumes the next block does *not* contain a coinbase solution, so the total supply,
+    /// cumulative weight, and last coinbase target and timestamp carry over from this block.
+    pub fn next_block_params(&self, proposed_timestamp: i64, proposed_round: u64) -> Result<Metadata<N>> {
+        // Compute the next height.
+        let height = self.height().checked_add(1).ok_or_else(|| anyhow!("The next block height overflowed"))?;
+        // Retarget the coinbase target and proof target.
+        let coinbase_target = coinbase_target(
+            self.last_coinbase_target(),
+            self.last_coinbase_timestamp(),
+            proposed_timestamp,
+            N::ANCHOR_TIME,
+            N::NUM_BLOCKS_PER_EPOCH,
+            N::GENESIS_COINBASE_TARGET,
+        )?;
+        let proof_target = proof_target(coinbase_target, N::GENESIS_PROOF_TARGET);
+
+        // Construct the next metadata.
+        let metadata = Metadata::new(
+            self.network(),
+            proposed_round,
+            height,
+            self.total_supply_in_microcredits(),
+            self.cumulative_weight(),
+            coinbase_target,
+            proof_target,
+            self.last_coinbase_target(),
+            self.last_coinbase_timestamp(),
+            proposed_timestamp,
+        )?;
+        // Ensure the next metadata is a valid successor.
+        self.metadata().is_valid_next(&metadata, proposed_timestamp)?;
+        Ok(metadata)
+    }
+}
+
 impl<N: Network> Block<N> {
     /// Returns the block hash.
     pub const fn hash(&self) -> N::BlockHash {
@@ -443,9 +512,80 @@ pub(crate) mod test_helpers {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use console::network::Testnet3;
 
     use indexmap::IndexMap;
 
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_next_block_params() {
+        // Load the genesis block.
+        let block = Block::<CurrentNetwork>::read_le(CurrentNetwork::genesis_bytes()).unwrap();
+        let timestamp = block.timestamp() + 10;
+
+        // Ensure the next metadata is a valid successor.
+        let metadata = block.next_block_params(timestamp, block.round() + 1).unwrap();
+        block.metadata().is_valid_next(&metadata, timestamp).unwrap();
+        assert_eq!(metadata.height(), block.height() + 1);
+        assert_eq!(metadata.round(), block.round() + 1);
+        assert_eq!(metadata.timestamp(), timestamp);
+        assert_eq!(metadata.total_supply_in_microcredits(), block.total_supply_in_microcredits());
+
+        // Ensure an incorrect round fails.
+        assert!(block.next_block_params(timestamp, block.round() + 2).is_err());
+        // Ensure a timestamp that does not advance fails.
+        assert!(block.next_block_params(block.timestamp(), block.round() + 1).is_err());
+    }
+
+    #[test]
+    fn test_transaction_inclusion_proof() {
+        // Load the genesis block.
+        let block = Block::<CurrentNetwork>::read_le(CurrentNetwork::genesis_bytes()).unwrap();
+        let transactions_root = block.transactions_root();
+        assert!(block.transactions().len() > 1);
+
+        // Ensure the proof for each transaction verifies.
+        for transaction_id in block.transaction_ids() {
+            let proof = block.to_transaction_inclusion_proof(transaction_id).unwrap();
+            assert!(Block::verify_transaction_inclusion_proof(&transactions_root, transaction_id, &proof));
+
+            // Ensure the proof fails for a different transaction ID, and for a different root.
+            let other_id = block.transaction_ids().find(|id| *id != transaction_id).unwrap();
+            assert!(!Block::verify_transaction_inclusion_proof(&transactions_root, other_id, &proof));
+            assert!(!Block::verify_transaction_inclusion_proof(&Field::zero(), transaction_id, &proof));
+        }
+
+        // Ensure a transaction ID that is not in the block fails.
+        let rng = &mut TestRng::default();
+        assert!(block.to_transaction_inclusion_proof(&Field::<CurrentNetwork>::rand(rng).into()).is_err());
+    }
+
+    #[test]
+    fn test_compute_expected_finalize_root() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the VM.
+        let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+        // Initialize a new caller.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+
+        // Construct a block containing a deployment.
+        let transaction = crate::vm::test_helpers::sample_deployment_transaction(rng);
+        let block = crate::vm::test_helpers::sample_next_block(&vm, &caller_private_key, &[transaction], rng).unwrap();
+
+        // Ensure the block contains finalize operations.
+        let operations = block.transactions().finalize_operations().cloned().collect::<Vec<_>>();
+        assert!(!operations.is_empty());
+
+        // Ensure the expected finalize root matches the one in the block header.
+        assert_eq!(Block::compute_expected_finalize_root(&operations).unwrap(), block.finalize_root());
+        assert_eq!(block.transactions().to_finalize_root().unwrap(), block.finalize_root());
+
+        // Ensure a different set of operations results in a different finalize root.
+        assert_ne!(Block::compute_expected_finalize_root(&operations[1..]).unwrap(), block.finalize_root());
+    }
+
     #[test]
     fn test_find_transaction_for_transition_id() {
         let rng = &mut TestRng::default();
@@ -0,0 +1,137 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Deployment<N> {
+    /// Returns the verifying keys of the deployment, packed into a single length-prefixed byte stream.
+    pub fn to_verifying_keys_bundle(&self) -> Result<Vec<u8>> {
+        Self::write_verifying_keys_bundle(
+            self.verifying_keys.iter().map(|(function_name, (verifying_key, _))| (function_name, verifying_key)),
+        )
+    }
+
+    /// Returns the given `(function_name, verifying_key)` pairs, packed into a single length-prefixed byte stream.
+    pub(crate) fn write_verifying_keys_bundle<'a>(
+        verifying_keys: impl ExactSizeIterator<Item = (&'a Identifier<N>, &'a VerifyingKey<N>)>,
+    ) -> Result<Vec<u8>> {
+        let mut bundle = Vec::new();
+        // Write the version.
+        0u8.write_le(&mut bundle)?;
+        // Write the number of entries in the bundle.
+        u16::try_from(verifying_keys.len())?.write_le(&mut bundle)?;
+        // Write each entry.
+        for (function_name, verifying_key) in verifying_keys {
+            // Write the function name.
+            function_name.write_le(&mut bundle)?;
+            // Write the verifying key, prefixed by its length.
+            let verifying_key = verifying_key.to_bytes_le()?;
+            u32::try_from(verifying_key.len())?.write_le(&mut bundle)?;
+            bundle.extend_from_slice(&verifying_key);
+        }
+        Ok(bundle)
+    }
+
+    /// Returns the `(function_name, verifying_key)` pairs from the given verifying keys bundle.
+    pub fn from_verifying_keys_bundle(bytes: &[u8]) -> Result<Vec<(Identifier<N>, VerifyingKey<N>)>> {
+        let mut reader = bytes;
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        ensure!(version == 0, "Invalid verifying keys bundle version");
+
+        // Read the number of entries in the bundle.
+        let num_entries = u16::read_le(&mut reader)?;
+        // Read each entry.
+        let mut verifying_keys = Vec::with_capacity(num_entries as usize);
+        for _ in 0..num_entries {
+            // Read the function name.
+            let function_name = Identifier::<N>::read_le(&mut reader)?;
+            // Read the length of the verifying key.
+            let num_bytes = u32::read_le(&mut reader)? as usize;
+            // Ensure the verifying key is within the bundle.
+            ensure!(num_bytes <= reader.len(), "The verifying key for '{function_name}' exceeds the bundle size");
+            // Read the verifying key.
+            let (verifying_key, remaining) = reader.split_at(num_bytes);
+            let verifying_key = VerifyingKey::<N>::from_bytes_le(verifying_key)?;
+            reader = remaining;
+            // Add the entry.
+            verifying_keys.push((function_name, verifying_key));
+        }
+
+        // Ensure the bundle is fully consumed.
+        ensure!(reader.is_empty(), "The verifying keys bundle contains trailing bytes");
+        // Ensure there are no duplicate function names.
+        ensure!(
+            !has_duplicates(verifying_keys.iter().map(|(function_name, _)| function_name)),
+            "A duplicate function name was found in the verifying keys bundle"
+        );
+        Ok(verifying_keys)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Process;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = circuit::network::AleoV0;
+
+    #[test]
+    fn test_verifying_keys_bundle() -> Result<()> {
+        // Initialize a new program with three functions.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program bundle.aleo;
+
+function add_one:
+    input r0 as u32.private;
+    add r0 1u32 into r1;
+    output r1 as u32.public;
+
+function add_two:
+    input r0 as u32.private;
+    input r1 as u32.public;
+    add r0 r1 into r2;
+    output r2 as u32.public;
+
+function negate:
+    input r0 as field.private;
+    neg r0 into r1;
+    output r1 as field.public;",
+        )?;
+
+        // Compute the deployment.
+        let deployment = Process::load()?.deploy::<CurrentAleo, _>(&program, &mut TestRng::default())?;
+        assert_eq!(3, deployment.verifying_keys().len());
+
+        // Pack and unpack the verifying keys.
+        let bundle = deployment.to_verifying_keys_bundle()?;
+        let candidate = Deployment::<CurrentNetwork>::from_verifying_keys_bundle(&bundle)?;
+
+        // Ensure the verifying keys match, in order.
+        assert_eq!(deployment.verifying_keys().len(), candidate.len());
+        for ((expected_name, (expected_key, _)), (name, key)) in deployment.verifying_keys().iter().zip_eq(&candidate) {
+            assert_eq!(expected_name, name);
+            assert_eq!(expected_key, key);
+        }
+
+        // Ensure a truncated or extended bundle fails.
+        assert!(Deployment::<CurrentNetwork>::from_verifying_keys_bundle(&bundle[..bundle.len() - 1]).is_err());
+        assert!(Deployment::<CurrentNetwork>::from_verifying_keys_bundle(&[&bundle[..], &[0u8]].concat()).is_err());
+        Ok(())
+    }
+}
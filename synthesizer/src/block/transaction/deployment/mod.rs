@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod bundle;
 mod bytes;
 mod serialize;
 mod string;
@@ -121,10 +122,53 @@ impl<N: Network> Deployment<N> {
         &self.verifying_keys
     }
 
+    /// Returns `true` if the deployment contains the function with the given name.
+    /// Note: This checks the program, whose functions correspond to the verifying keys (see `check_is_ordered`).
+    pub fn contains_function(&self, name: &Identifier<N>) -> bool {
+        self.program.contains_function(name)
+    }
+
+    /// Returns the names of the deployed functions, in the order of the verifying keys.
+    pub fn function_names(&self) -> impl '_ + Iterator<Item = &Identifier<N>> {
+        self.verifying_keys.iter().map(|(function_name, _)| function_name)
+    }
+
+    /// Returns a copy of the deployment with every certificate replaced by an empty certificate.
+    /// Note: The stripped deployment does not pass `verify_deployment`, and is intended for storage and indexing.
+    pub fn strip_certificates(&self) -> Deployment<N> {
+        Self {
+            edition: self.edition,
+            program: self.program.clone(),
+            verifying_keys: self
+                .verifying_keys
+                .iter()
+                .map(|(function_name, (verifying_key, _))| {
+                    (*function_name, (verifying_key.clone(), Certificate::empty()))
+                })
+                .collect(),
+        }
+    }
+
     /// Returns the deployment ID.
     pub fn to_deployment_id(&self) -> Result<Field<N>> {
         Ok(*Transaction::deployment_tree(self, None)?.root())
     }
+
+    /// Returns the estimated time in milliseconds to prove all functions in the deployment.
+    pub fn estimated_proving_time_ms(&self) -> Result<u64> {
+        // Sum the number of constraints across all functions.
+        let num_constraints =
+            self.verifying_keys.iter().try_fold(0u64, |num_constraints, (_, (verifying_key, _))| {
+                num_constraints
+                    .checked_add(u64::try_from(verifying_key.circuit_info.num_constraints)?)
+                    .ok_or_else(|| anyhow!("Overflow while counting the constraints in the deployment"))
+            })?;
+        // Compute the estimated proving time, rounding up.
+        let num_constraints = num_constraints
+            .checked_add(N::CONSTRAINTS_PER_MS - 1)
+            .ok_or_else(|| anyhow!("Overflow while estimating the proving time of the deployment"))?;
+        Ok(num_constraints / N::CONSTRAINTS_PER_MS)
+    }
 }
 
 #[cfg(test)]
@@ -167,3 +211,123 @@ function compute:
             .clone()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Process;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = circuit::network::AleoV0;
+
+    #[test]
+    fn test_estimated_proving_time_ms() {
+        let rng = &mut TestRng::default();
+
+        // Ensure the estimate is positive for a non-trivial deployment.
+        let deployment = test_helpers::sample_deployment();
+        let estimate = deployment.estimated_proving_time_ms().unwrap();
+        assert!(estimate > 0);
+
+        // Initialize a new program with an additional function.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program testing.aleo;
+
+function compute:
+    input r0 as u32.private;
+    input r1 as u32.public;
+    add r0 r1 into r2;
+    output r2 as u32.public;
+
+function compute_twice:
+    input r0 as u32.private;
+    input r1 as u32.public;
+    add r0 r1 into r2;
+    add r2 r1 into r3;
+    output r3 as u32.public;",
+        )
+        .unwrap();
+
+        // Compute the deployment.
+        let process = Process::load().unwrap();
+        let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+
+        // Ensure adding a function increases the estimate.
+        assert!(deployment.estimated_proving_time_ms().unwrap() > estimate);
+    }
+
+    #[test]
+    fn test_strip_certificates() {
+        let rng = &mut TestRng::default();
+
+        // Retrieve a deployment, and strip its certificates.
+        let deployment = test_helpers::sample_deployment();
+        let stripped = deployment.strip_certificates();
+
+        // Ensure the program and verifying keys are preserved.
+        assert_eq!(deployment.edition(), stripped.edition());
+        assert_eq!(deployment.program(), stripped.program());
+        assert_eq!(deployment.verifying_keys().len(), stripped.verifying_keys().len());
+        for ((name, (verifying_key, certificate)), (stripped_name, (stripped_key, stripped_certificate))) in
+            deployment.verifying_keys().iter().zip(stripped.verifying_keys())
+        {
+            assert_eq!(name, stripped_name);
+            assert_eq!(verifying_key, stripped_key);
+            assert!(!certificate.is_empty());
+            assert!(stripped_certificate.is_empty());
+            // Ensure the certificate produced by the deployment is well-formed, and the stripped one is not.
+            assert!(certificate.is_well_formed());
+            assert!(!stripped_certificate.is_well_formed());
+        }
+
+        // Ensure the stripped deployment fails verification.
+        let process = Process::load().unwrap();
+        assert!(process.verify_deployment::<CurrentAleo, _>(&deployment, rng).is_ok());
+        assert!(process.verify_deployment::<CurrentAleo, _>(&stripped, rng).is_err());
+    }
+
+    #[test]
+    fn test_function_names() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new program with two functions.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program testing.aleo;
+
+function compute:
+    input r0 as u32.private;
+    output r0 as u32.public;
+
+function compute_twice:
+    input r0 as u32.private;
+    add r0 r0 into r1;
+    output r1 as u32.public;",
+        )
+        .unwrap();
+
+        // Compute the deployment.
+        let process = Process::load().unwrap();
+        let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+
+        // Ensure exactly the two function names are returned.
+        let expected = [Identifier::from_str("compute").unwrap(), Identifier::from_str("compute_twice").unwrap()];
+        assert_eq!(deployment.function_names().collect::<Vec<_>>(), expected.iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_contains_function() {
+        // Retrieve a deployment.
+        let deployment = test_helpers::sample_deployment();
+
+        // Ensure a deployed function is found.
+        let compute = Identifier::from_str("compute").unwrap();
+        assert!(deployment.contains_function(&compute));
+        assert!(deployment.verifying_keys().iter().any(|(name, _)| name == &compute));
+
+        // Ensure a function that is not deployed is not found.
+        assert!(!deployment.contains_function(&Identifier::from_str("compute_twice").unwrap()));
+    }
+}
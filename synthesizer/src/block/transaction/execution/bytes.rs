@@ -91,4 +91,14 @@ mod tests {
         assert!(Execution::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
         Ok(())
     }
+
+    #[test]
+    fn test_size_in_bytes() -> Result<()> {
+        // Construct a new execution.
+        let execution = crate::process::test_helpers::sample_execution();
+
+        // Ensure the size matches the length of the byte representation.
+        assert_eq!(execution.size_in_bytes()?, execution.to_bytes_le()?.len() as u64);
+        Ok(())
+    }
 }
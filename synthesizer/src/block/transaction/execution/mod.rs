@@ -54,9 +54,11 @@ impl<N: Network> Execution<N> {
         Ok(execution)
     }
 
-    /// Returns the size in bytes.
+    /// Returns the size in bytes, without allocating the byte representation.
     pub fn size_in_bytes(&self) -> Result<u64> {
-        Ok(u64::try_from(self.to_bytes_le()?.len())?)
+        let mut counter = ByteCounter::default();
+        self.write_le(&mut counter)?;
+        Ok(counter.0)
     }
 
     /// Returns the global state root.
@@ -73,6 +75,12 @@ impl<N: Network> Execution<N> {
     pub fn to_execution_id(&self) -> Result<Field<N>> {
         Ok(*Transaction::execution_tree(self, &None)?.root())
     }
+
+    /// Returns the ID that a fee must be bound to, in order to pay for this execution.
+    /// Note: The fee transition takes this value as its third input, see `Process::verify_fee`.
+    pub fn to_fee_binding_hash(&self) -> Result<Field<N>> {
+        self.to_execution_id()
+    }
 }
 
 impl<N: Network> Execution<N> {
@@ -139,3 +147,50 @@ impl<N: Network> Execution<N> {
         self.transitions.values().flat_map(Transition::commitments)
     }
 }
+
+/// A writer that discards its input, and counts the number of bytes written.
+#[derive(Default)]
+struct ByteCounter(u64);
+
+impl Write for ByteCounter {
+    fn write(&mut self, buf: &[u8]) -> IoResult<usize> {
+        self.0 = self.0.checked_add(buf.len() as u64).ok_or_else(|| error("Overflow while counting bytes"))?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> IoResult<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::block::Input;
+    use console::program::{Literal, Plaintext};
+
+    #[test]
+    fn test_to_fee_binding_hash() {
+        let rng = &mut TestRng::default();
+
+        // Fetch an execution transaction with a fee.
+        let transaction = crate::vm::test_helpers::sample_execution_transaction_with_fee(rng);
+
+        match transaction {
+            Transaction::Execute(_, execution, Some(fee)) => {
+                // Compute the fee binding hash.
+                let binding_hash = execution.to_fee_binding_hash().unwrap();
+                assert_eq!(binding_hash, execution.to_execution_id().unwrap());
+
+                // Ensure the binding hash matches the ID in the fee transition.
+                match fee.inputs().get(2) {
+                    Some(Input::Public(_, Some(Plaintext::Literal(Literal::Field(id), _)))) => {
+                        assert_eq!(*id, binding_hash)
+                    }
+                    _ => panic!("Expected a public field input in the fee"),
+                }
+            }
+            _ => panic!("Expected an execution transaction with a fee"),
+        }
+    }
+}
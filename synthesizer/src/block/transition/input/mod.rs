@@ -214,3 +214,37 @@ pub(crate) mod test_helpers {
         ]
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_serial_number_and_tag() {
+        let rng = &mut TestRng::default();
+
+        // Sample the fields.
+        let id = Field::<CurrentNetwork>::rand(rng);
+        let serial_number = Field::<CurrentNetwork>::rand(rng);
+        let tag = Field::<CurrentNetwork>::rand(rng);
+
+        // Ensure only the record input has a serial number and tag.
+        for input in [
+            Input::Constant(id, None),
+            Input::Public(id, None),
+            Input::Private(id, None),
+            Input::ExternalRecord(id),
+        ] {
+            assert_eq!(input.serial_number(), None);
+            assert_eq!(input.tag(), None);
+        }
+        let input = Input::<CurrentNetwork>::Record(serial_number, tag);
+        assert_eq!(input.serial_number(), Some(&serial_number));
+        assert_eq!(input.tag(), Some(&tag));
+        assert_eq!(input.clone().into_serial_number(), Some(serial_number));
+        assert_eq!(input.into_tag(), Some(tag));
+    }
+}
@@ -0,0 +1,85 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A minimal representation of a transition, for public distribution.
+/// Note: The proof for a transition is held by its execution, and is not included here.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MinimalTransition<N: Network> {
+    /// The transition ID.
+    id: N::TransitionID,
+    /// The constant and public inputs.
+    public_inputs: Vec<Input<N>>,
+    /// The record commitments.
+    commitments: Vec<Field<N>>,
+}
+
+impl<N: Network> MinimalTransition<N> {
+    /// Returns the transition ID.
+    pub const fn id(&self) -> &N::TransitionID {
+        &self.id
+    }
+
+    /// Returns the constant and public inputs.
+    pub fn public_inputs(&self) -> &[Input<N>] {
+        &self.public_inputs
+    }
+
+    /// Returns the record commitments.
+    pub fn commitments(&self) -> &[Field<N>] {
+        &self.commitments
+    }
+}
+
+impl<N: Network> Transition<N> {
+    /// Returns the minimal representation of the transition, which omits the private inputs and record inputs.
+    pub fn to_minimal_repr(&self) -> MinimalTransition<N> {
+        MinimalTransition {
+            id: self.id,
+            public_inputs: self
+                .inputs
+                .iter()
+                .filter(|input| matches!(input, Input::Constant(..) | Input::Public(..)))
+                .cloned()
+                .collect(),
+            commitments: self.commitments().copied().collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_minimal_repr() {
+        // Sample a transition.
+        let transition = crate::process::test_helpers::sample_transition();
+        let minimal = transition.to_minimal_repr();
+
+        // Ensure the transition ID and record commitments are preserved.
+        assert_eq!(minimal.id(), transition.id());
+        assert_eq!(minimal.commitments(), transition.commitments().copied().collect::<Vec<_>>());
+
+        // Ensure the public inputs are preserved.
+        let expected =
+            transition.inputs().iter().filter(|input| matches!(input, Input::Constant(..) | Input::Public(..)));
+        assert_eq!(minimal.public_inputs().iter().collect::<Vec<_>>(), expected.collect::<Vec<_>>());
+
+        // Ensure the private inputs and record tags are absent.
+        assert!(minimal.public_inputs().iter().all(|input| input.tag().is_none()));
+        assert!(!minimal.public_inputs().iter().any(|input| matches!(input, Input::Private(..))));
+    }
+}
@@ -18,18 +18,23 @@ pub use input::Input;
 pub mod output;
 pub use output::Output;
 
+mod minimal;
+pub use minimal::MinimalTransition;
+
 mod bytes;
 mod merkle;
 mod serialize;
 mod string;
 
 use console::{
+    account::ViewKey,
     network::prelude::*,
     program::{
         Ciphertext,
         Identifier,
         InputID,
         OutputID,
+        Plaintext,
         ProgramID,
         Record,
         Register,
@@ -307,6 +312,17 @@ impl<N: Network> Transition<N> {
         false
     }
 
+    /// Returns `true` if this is a `fee` transition.
+    #[inline]
+    pub fn is_fee(&self) -> bool {
+        // Case 1 - The transition calls 'credits.aleo/fee'.
+        if self.program_id.to_string() == "credits.aleo" && self.function_name.to_string() == "fee" {
+            return true;
+        }
+        // Otherwise, return 'false'.
+        false
+    }
+
     /// Returns `true` if this is a `split` transition.
     #[inline]
     pub fn is_split(&self) -> bool {
@@ -375,6 +391,11 @@ impl<N: Network> Transition<N> {
         self.inputs.iter().flat_map(Input::tag)
     }
 
+    /// Returns the number of serial numbers, for inputs that are records.
+    pub fn serial_number_count(&self) -> usize {
+        self.inputs.iter().filter(|input| matches!(input, Input::Record(..))).count()
+    }
+
     /* Output */
 
     /// Returns the output IDs.
@@ -387,11 +408,27 @@ impl<N: Network> Transition<N> {
         self.outputs.iter().flat_map(Output::commitment)
     }
 
+    /// Returns the number of commitments, for outputs that are records.
+    pub fn commitment_count(&self) -> usize {
+        self.outputs.iter().filter(|output| matches!(output, Output::Record(..))).count()
+    }
+
     /// Returns an iterator over the nonces, for outputs that are records.
     pub fn nonces(&self) -> impl '_ + Iterator<Item = &Group<N>> {
         self.outputs.iter().flat_map(Output::nonce)
     }
 
+    /// Returns the output record of a fee transition, decrypted with the given view key.
+    pub fn to_fee_record(&self, view_key: &ViewKey<N>) -> Result<Record<N, Plaintext<N>>> {
+        // Ensure this is a fee transition.
+        ensure!(self.is_fee(), "Transition '{}' is not a fee transition", self.id);
+        // Retrieve the first output record, and decrypt it.
+        match self.records().next() {
+            Some((_, record)) => record.decrypt(view_key),
+            None => bail!("Fee transition '{}' does not contain an output record", self.id),
+        }
+    }
+
     /// Returns an iterator over the output records, as a tuple of `(commitment, record)`.
     pub fn records(&self) -> impl '_ + Iterator<Item = (&Field<N>, &Record<N, Ciphertext<N>>)> {
         self.outputs.iter().flat_map(Output::record)
@@ -452,3 +489,48 @@ impl<N: Network> Transition<N> {
         self.finalize.into_iter().flatten()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_commitment_and_serial_number_count() {
+        // Sample a transition.
+        let transition = crate::process::test_helpers::sample_transition();
+
+        // Ensure the counts match the number of record inputs and outputs.
+        let expected_serial_numbers =
+            transition.inputs().iter().filter(|input| matches!(input, Input::Record(..))).count();
+        let expected_commitments =
+            transition.outputs().iter().filter(|output| matches!(output, Output::Record(..))).count();
+        assert_eq!(transition.serial_number_count(), expected_serial_numbers);
+        assert_eq!(transition.commitment_count(), expected_commitments);
+
+        // Ensure the counts match the iterators.
+        assert_eq!(transition.serial_number_count(), transition.serial_numbers().count());
+        assert_eq!(transition.commitment_count(), transition.commitments().count());
+    }
+
+    #[test]
+    fn test_to_fee_record() {
+        let rng = &mut TestRng::default();
+
+        // Sample a fee, and retrieve the view key of its caller.
+        let fee = crate::vm::test_helpers::sample_fee(rng);
+        let private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let view_key = ViewKey::try_from(&private_key).unwrap();
+
+        // Ensure the fee record is the decrypted output record.
+        assert!(fee.is_fee());
+        let record = fee.to_fee_record(&view_key).unwrap();
+        let (_, expected) = fee.records().next().unwrap();
+        assert_eq!(record, expected.decrypt(&view_key).unwrap());
+        assert!(expected.is_owner(&view_key));
+
+        // Ensure a non-fee transition fails.
+        let transition = crate::process::test_helpers::sample_transition();
+        assert!(!transition.is_fee());
+        assert!(transition.to_fee_record(&view_key).is_err());
+    }
+}
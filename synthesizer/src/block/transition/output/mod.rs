@@ -267,3 +267,35 @@ pub(crate) mod test_helpers {
         ]
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_id() {
+        let rng = &mut TestRng::default();
+
+        // Sample the fields.
+        let id = Field::<CurrentNetwork>::rand(rng);
+        let checksum = Field::<CurrentNetwork>::rand(rng);
+
+        // Ensure the ID is the value hash for the constant, public, and private outputs.
+        for output in [Output::Constant(id, None), Output::Public(id, None), Output::Private(id, None)] {
+            assert_eq!(output.id(), &id);
+            assert_eq!(output.commitment(), None);
+        }
+        // Ensure the ID is the commitment for the record output.
+        let output = Output::<CurrentNetwork>::Record(id, checksum, None);
+        assert_eq!(output.id(), &id);
+        assert_eq!(output.commitment(), Some(&id));
+        assert_eq!(output.checksum(), Some(&checksum));
+        // Ensure the ID is the output commitment for the external record output.
+        let output = Output::<CurrentNetwork>::ExternalRecord(id);
+        assert_eq!(output.id(), &id);
+        assert_eq!(output.commitment(), None);
+    }
+}
@@ -37,6 +37,40 @@ impl<N: Network> Process<N> {
         deployment
     }
 
+    /// Synthesizes the proving and verifying key for each function in the given program.
+    #[inline]
+    pub fn compile<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        program: &Program<N>,
+        rng: &mut R,
+    ) -> Result<Vec<(Identifier<N>, ProvingKey<N>, VerifyingKey<N>)>> {
+        let timer = timer!("Process::compile");
+
+        // Ensure the program contains functions.
+        ensure!(!program.functions().is_empty(), "Program '{}' has no functions", program.id());
+
+        // Compute the stack.
+        let stack = Stack::initialize(self, program)?;
+        lap!(timer, "Compute the stack");
+
+        // Synthesize the proving and verifying key for each function.
+        let keys = program
+            .functions()
+            .keys()
+            .map(|function_name| {
+                // Synthesize the proving and verifying key.
+                stack.synthesize_key::<A, R>(function_name, rng)?;
+                lap!(timer, "Synthesize key for {function_name}");
+                // Retrieve the proving and verifying key.
+                Ok((*function_name, stack.get_proving_key(function_name)?, stack.get_verifying_key(function_name)?))
+            })
+            .collect::<Result<Vec<_>>>();
+
+        finish!(timer);
+
+        keys
+    }
+
     /// Verifies the given deployment is ordered.
     #[inline]
     pub fn verify_deployment<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
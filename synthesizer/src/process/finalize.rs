@@ -127,21 +127,7 @@ impl<N: Network> Process<N> {
                     )?;
 
                     // Evaluate the commands.
-                    for command in finalize.commands() {
-                        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
-                            command.finalize(stack, store, &mut registers)
-                        }));
-                        match result {
-                            // If the evaluation succeeds with an operation, add it to the list.
-                            Ok(Ok(Some(finalize_operation))) => finalize_operations.push(finalize_operation),
-                            // If the evaluation succeeds with no operation, continue.
-                            Ok(Ok(None)) => (),
-                            // If the evaluation fails, bail and return the error.
-                            Ok(Err(error)) => bail!("'finalize' failed to evaluate command ({command}): {error}"),
-                            // If the evaluation fails, bail and return the error.
-                            Err(_) => bail!("'finalize' failed to evaluate command ({command})"),
-                        }
-                    }
+                    finalize_operations.extend(finalize.evaluate_commands(stack, store, &mut registers)?);
 
                     lap!(timer, "Finalize transition for {function_name}");
                 }
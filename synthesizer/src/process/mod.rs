@@ -15,6 +15,9 @@
 mod stack;
 pub use stack::*;
 
+mod statistics;
+pub use statistics::ProcessStatistics;
+
 mod trace;
 pub use trace::*;
 
@@ -24,6 +27,9 @@ mod evaluate;
 mod execute;
 mod execute_fee;
 mod finalize;
+mod reload;
+mod snapshot;
+mod validate;
 mod verify_execution;
 mod verify_fee;
 
@@ -93,6 +99,24 @@ impl<N: Network> Process<N> {
         Ok(process)
     }
 
+    /// Synthesizes the verifying keys for the 'credits.aleo' functions, as used to construct a genesis block.
+    #[inline]
+    pub fn derive_genesis_verifying_keys<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        rng: &mut R,
+    ) -> Result<HashMap<Identifier<N>, VerifyingKey<N>>> {
+        // Initialize a process, synthesizing the 'credits.aleo' circuit keys.
+        let process = Self::setup::<A, R>(rng)?;
+        // Retrieve the 'credits.aleo' stack.
+        let stack = process.get_stack("credits.aleo")?;
+        // Return the verifying keys.
+        stack
+            .program()
+            .functions()
+            .keys()
+            .map(|function_name| Ok((*function_name, stack.get_verifying_key(function_name)?)))
+            .collect()
+    }
+
     /// Adds a new program to the process.
     /// If you intend to `execute` the program, use `deploy` and `finalize_deployment` instead.
     #[inline]
@@ -226,6 +250,12 @@ impl<N: Network> Process<N> {
         self.stacks.contains_key(program_id)
     }
 
+    /// Returns an iterator over the program IDs of all loaded programs, in order of insertion.
+    #[inline]
+    pub fn iter_program_ids(&self) -> impl '_ + Iterator<Item = &ProgramID<N>> {
+        self.stacks.keys()
+    }
+
     /// Returns the stack for the given program ID.
     #[inline]
     pub fn get_stack(&self, program_id: impl TryInto<ProgramID<N>>) -> Result<&Stack<N>> {
@@ -271,6 +301,21 @@ impl<N: Network> Process<N> {
         self.get_stack(program_id)?.get_verifying_key(&function_name)
     }
 
+    /// Returns the names of the functions in the given program without a verifying key, in order of declaration.
+    #[inline]
+    pub fn missing_verifying_keys(&self, program_id: &ProgramID<N>) -> Result<Vec<Identifier<N>>> {
+        // Retrieve the stack.
+        let stack = self.get_stack(program_id)?;
+        // Return the function names without a verifying key.
+        Ok(stack
+            .program()
+            .functions()
+            .keys()
+            .filter(|function_name| !stack.contains_verifying_key(function_name))
+            .copied()
+            .collect())
+    }
+
     /// Inserts the given proving key, for the given program ID and function name.
     #[inline]
     pub fn insert_proving_key(
@@ -0,0 +1,69 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use std::path::Path;
+
+impl<N: Network> Process<N> {
+    /// Reloads the verifying keys from the given directory, replacing the keys of the loaded programs.
+    /// Each verifying key is expected in a file named `<program_id>_<function_name>.vk`.
+    ///
+    /// Note: All of the verifying keys are loaded and validated before any of them are inserted,
+    /// so that on failure, the verifying keys of the process are unchanged.
+    #[inline]
+    pub fn reload_verifying_keys_from_dir(&self, dir: &Path) -> Result<()> {
+        // Ensure the directory exists.
+        ensure!(dir.is_dir(), "Verifying key directory '{}' does not exist", dir.display());
+
+        // Load the verifying keys.
+        let mut verifying_keys = Vec::new();
+        for entry in std::fs::read_dir(dir)? {
+            let path = entry?.path();
+            // Skip any entry that is not a verifying key file.
+            if !path.is_file() || path.extension().and_then(|extension| extension.to_str()) != Some("vk") {
+                continue;
+            }
+            // Retrieve the file stem.
+            let file_stem = match path.file_stem().and_then(|file_stem| file_stem.to_str()) {
+                Some(file_stem) => file_stem,
+                None => bail!("Invalid verifying key file name '{}'", path.display()),
+            };
+            // Determine the loaded program that the file belongs to.
+            let (stack, function_name) = match self
+                .stacks
+                .iter()
+                .find_map(|(program_id, stack)| Some((stack, file_stem.strip_prefix(&format!("{program_id}_"))?)))
+            {
+                Some((stack, function_name)) => (stack, Identifier::from_str(function_name)?),
+                None => bail!("Verifying key file '{}' does not belong to a loaded program", path.display()),
+            };
+            // Ensure the function exists in the program.
+            ensure!(
+                stack.program().contains_function(&function_name),
+                "Verifying key file '{}' does not belong to a function in '{}'",
+                path.display(),
+                stack.program_id()
+            );
+            // Load the verifying key.
+            verifying_keys.push((stack, function_name, VerifyingKey::from_file(&path)?));
+        }
+
+        // Insert the verifying keys.
+        for (stack, function_name, verifying_key) in verifying_keys {
+            stack.insert_verifying_key(&function_name, verifying_key)?;
+        }
+        Ok(())
+    }
+}
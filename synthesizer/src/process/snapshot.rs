@@ -0,0 +1,121 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use std::path::Path;
+
+/// The file extension of a program snapshot.
+const SNAPSHOT_EXTENSION: &str = "snapshot";
+
+impl<N: Network> Process<N> {
+    /// Exports the loaded programs and their verifying keys to the given directory,
+    /// writing one `<program_id>.snapshot` file per program.
+    #[inline]
+    pub fn export_state(&self, dir: &Path) -> Result<()> {
+        // Ensure the directory exists.
+        ensure!(dir.is_dir(), "Snapshot directory '{}' does not exist", dir.display());
+
+        for (program_id, stack) in &self.stacks {
+            // Retrieve the verifying keys of the program.
+            let verifying_keys = stack
+                .program()
+                .functions()
+                .keys()
+                .filter(|function_name| stack.contains_verifying_key(function_name))
+                .map(|function_name| Ok((*function_name, stack.get_verifying_key(function_name)?)))
+                .collect::<Result<Vec<_>>>()?;
+
+            // Write the program, followed by the verifying keys bundle.
+            let mut bytes = stack.program().to_bytes_le()?;
+            bytes.extend(Deployment::write_verifying_keys_bundle(
+                verifying_keys.iter().map(|(function_name, verifying_key)| (function_name, verifying_key)),
+            )?);
+            std::fs::write(dir.join(format!("{program_id}.{SNAPSHOT_EXTENSION}")), bytes)?;
+        }
+        Ok(())
+    }
+
+    /// Imports the programs and their verifying keys from the given directory, in dependency order.
+    /// Programs that are already loaded must match the snapshot, and only have their verifying keys updated.
+    ///
+    /// Note: All of the snapshots are read and validated against a staged copy of the process,
+    /// and the process is only updated if every snapshot is valid.
+    #[inline]
+    pub fn import_state(&mut self, dir: &Path) -> Result<()> {
+        // Ensure the directory exists.
+        ensure!(dir.is_dir(), "Snapshot directory '{}' does not exist", dir.display());
+
+        // Read the snapshots.
+        let mut pending = Vec::new();
+        for entry in std::fs::read_dir(dir)? {
+            let path = entry?.path();
+            // Skip any entry that is not a snapshot file.
+            if !path.is_file() || path.extension().and_then(|extension| extension.to_str()) != Some(SNAPSHOT_EXTENSION) {
+                continue;
+            }
+            let bytes = std::fs::read(&path)?;
+            let mut reader = &bytes[..];
+            // Read the program.
+            let program = Program::<N>::read_le(&mut reader)?;
+            // Read the verifying keys bundle.
+            let verifying_keys = Deployment::from_verifying_keys_bundle(reader)?;
+            pending.push((program, verifying_keys));
+        }
+
+        // Stage the programs on a copy of the process, once all of their imports are loaded.
+        // Note: The verifying keys are only inserted on commit, as the staged stacks share them with the process.
+        let mut staged = self.clone();
+        let mut staged_verifying_keys = Vec::with_capacity(pending.len());
+        while !pending.is_empty() {
+            let index = match pending
+                .iter()
+                .position(|(program, _)| program.imports().keys().all(|import| staged.contains_program(import)))
+            {
+                Some(index) => index,
+                None => bail!("Failed to import the snapshot, as it contains programs with missing imports"),
+            };
+            let (program, verifying_keys) = pending.swap_remove(index);
+
+            match staged.contains_program(program.id()) {
+                // Ensure the loaded program matches the snapshot.
+                true => ensure!(
+                    staged.get_program(program.id())? == &program,
+                    "Program '{}' is already loaded, and does not match the snapshot",
+                    program.id()
+                ),
+                // Add the program.
+                false => staged.add_program(&program)?,
+            }
+            // Ensure the verifying keys belong to functions in the program.
+            for (function_name, _) in &verifying_keys {
+                ensure!(
+                    program.contains_function(function_name),
+                    "Function '{function_name}' does not exist in program '{}'",
+                    program.id()
+                );
+            }
+            staged_verifying_keys.push((*program.id(), verifying_keys));
+        }
+
+        // Commit the staged programs and their verifying keys.
+        *self = staged;
+        for (program_id, verifying_keys) in staged_verifying_keys {
+            for (function_name, verifying_key) in verifying_keys {
+                self.insert_verifying_key(&program_id, &function_name, verifying_key)?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -54,6 +54,11 @@ impl<N: Network> Authorization<N> {
         self.requests.read().len()
     }
 
+    /// Returns the number of transitions the authorization will produce, one per `Request`.
+    pub fn transition_count(&self) -> usize {
+        self.len()
+    }
+
     /// Return `true` if the authorization is empty.
     pub fn is_empty(&self) -> bool {
         self.requests.read().is_empty()
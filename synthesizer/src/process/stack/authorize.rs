@@ -31,23 +31,19 @@ impl<N: Network> Stack<N> {
 
         // Prepare the function name.
         let function_name = function_name.try_into().map_err(|_| anyhow!("Invalid function name"))?;
-        // Retrieve the function.
-        let function = self.get_function(&function_name)?;
         // Retrieve the input types.
-        let input_types = function.input_types();
-        // Ensure the number of inputs matches the number of input types.
-        if inputs.len() != input_types.len() {
-            bail!(
-                "Function '{function_name}' in program '{}' expects {} inputs, but {} inputs were found.",
-                self.program.id(),
-                input_types.len(),
-                inputs.len()
-            )
-        }
-        lap!(timer, "Verify the number of inputs");
+        let input_types = self.get_function(&function_name)?.input_types();
+        // Prepare the inputs.
+        let inputs = inputs
+            .map(|input| input.try_into().map_err(|_| anyhow!("Failed to parse an input for '{function_name}'")))
+            .collect::<Result<Vec<Value<N>>>>()?;
+        // Ensure the inputs match the declared input types.
+        self.verify_function_inputs(&function_name, &inputs)?;
+        lap!(timer, "Verify the inputs");
 
         // Compute the request.
-        let request = Request::sign(private_key, *self.program.id(), function_name, inputs, &input_types, rng)?;
+        let request =
+            Request::sign(private_key, *self.program.id(), function_name, inputs.into_iter(), &input_types, rng)?;
         lap!(timer, "Compute the request");
         // Initialize the authorization.
         let authorization = Authorization::new(&[request.clone()]);
@@ -62,4 +58,32 @@ impl<N: Network> Stack<N> {
         // Return the authorization.
         Ok(authorization)
     }
+
+    /// Checks that the given inputs match the types and visibilities declared by the function.
+    #[inline]
+    pub fn verify_function_inputs(&self, function_name: &Identifier<N>, inputs: &[Value<N>]) -> Result<()> {
+        // Retrieve the function.
+        let function = self.get_function(function_name)?;
+        // Retrieve the input types.
+        let input_types = function.input_types();
+        // Ensure the number of inputs matches the number of input types.
+        if inputs.len() != input_types.len() {
+            bail!(
+                "Function '{function_name}' in program '{}' expects {} inputs, but {} inputs were found.",
+                self.program.id(),
+                input_types.len(),
+                inputs.len()
+            )
+        }
+        // Ensure each input matches its declared input type.
+        for (index, (input, input_type)) in inputs.iter().zip_eq(&input_types).enumerate() {
+            if let Err(error) = self.matches_value_type(input, input_type) {
+                bail!(
+                    "Input {index} of function '{function_name}' in program '{}' does not match '{input_type}': {error}",
+                    self.program.id()
+                )
+            }
+        }
+        Ok(())
+    }
 }
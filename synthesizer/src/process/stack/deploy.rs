@@ -75,6 +75,10 @@ impl<N: Network> Stack<N> {
         for (function, (_, (verifying_key, certificate))) in
             deployment.program().functions().values().zip_eq(deployment.verifying_keys())
         {
+            // Ensure the certificate is well-formed, before synthesizing the circuit.
+            if !certificate.is_well_formed() {
+                bail!("The certificate for function '{}' is malformed in '{program_id}'", function.name())
+            }
             // Initialize a burner private key.
             let burner_private_key = PrivateKey::new(rng)?;
             // Compute the burner address.
@@ -475,5 +475,12 @@ impl<N: Network> Stack<N> {
             scope.into().bold(),
             width = 20
         );
+
+        // Print the variable values, if tracing is enabled.
+        if enabled!(tracing::Level::TRACE) {
+            for (name, bits) in A::eject_all_values().into_iter().sorted() {
+                trace!("{name}: {}", bits.into_iter().rev().map(|bit| if bit { '1' } else { '0' }).collect::<String>());
+            }
+        }
     }
 }
@@ -69,4 +69,92 @@ impl<N: Network> FinalizeRegisters<N> {
     pub const fn state(&self) -> &FinalizeGlobalState {
         &self.state
     }
+
+    /// Returns an iterator over the assigned registers, as `(locator, value)` pairs in order of assignment.
+    #[inline]
+    pub fn iter_registers(&self) -> impl '_ + Iterator<Item = (u64, &Plaintext<N>)> {
+        self.registers.iter().map(|(locator, value)| (*locator, value))
+    }
+
+    /// Returns a human-readable dump of the assigned registers, with one `rN = <value>` line per register.
+    pub fn to_debug_string(&self) -> String {
+        self.iter_registers().map(|(locator, value)| format!("r{locator} = {value}\n")).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Process, Program, Stack};
+    use console::{network::Testnet3, program::Identifier};
+
+    type CurrentNetwork = Testnet3;
+
+    /// Samples the finalize registers, with the given literals stored in order.
+    fn sample_finalize_registers(literals: &[&str]) -> Result<FinalizeRegisters<CurrentNetwork>> {
+        // Initialize the program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program testing.aleo;
+
+function run:
+    input r0 as u8.public;
+    input r1 as field.public;
+    input r2 as boolean.public;
+    finalize r0 r1 r2;
+
+finalize run:
+    input r0 as u8.public;
+    input r1 as field.public;
+    input r2 as boolean.public;
+    is.eq r0 r0 into r3;",
+        )?;
+        // Initialize the stack.
+        let stack = Stack::new(&Process::load()?, &program)?;
+        let function_name = Identifier::from_str("run")?;
+
+        // Initialize the registers.
+        let mut registers =
+            FinalizeRegisters::new(FinalizeGlobalState::new(1), stack.get_finalize_types(&function_name)?.clone());
+        // Store each literal in the registers.
+        for (index, literal) in literals.iter().enumerate() {
+            let value = Value::Plaintext(Plaintext::from(Literal::from_str(literal)?));
+            registers.store(&stack, &Register::Locator(index as u64), value)?;
+        }
+        Ok(registers)
+    }
+
+    #[test]
+    fn test_iter_registers() -> Result<()> {
+        // Ensure an empty set of registers yields nothing.
+        let registers = sample_finalize_registers(&[])?;
+        assert_eq!(0, registers.iter_registers().count());
+
+        // Ensure the registers are yielded in order, with the correct locators.
+        let literals = ["1u8", "2field", "true"];
+        let registers = sample_finalize_registers(&literals)?;
+        let candidate = registers.iter_registers().collect::<Vec<_>>();
+        assert_eq!(literals.len(), candidate.len());
+        for (index, (expected, (locator, value))) in literals.iter().zip_eq(candidate).enumerate() {
+            assert_eq!(index as u64, locator);
+            assert_eq!(&Plaintext::from(Literal::from_str(expected)?), value);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_debug_string() -> Result<()> {
+        // Ensure an empty set of registers yields an empty string.
+        let registers = sample_finalize_registers(&[])?;
+        assert_eq!("", registers.to_debug_string());
+
+        // Ensure each register is printed on its own line.
+        let registers = sample_finalize_registers(&["1u8", "2field", "true"])?;
+        let candidate = registers.to_debug_string();
+        assert!(candidate.contains("r0 = 1u8\n"));
+        assert!(candidate.contains("r1 = 2field\n"));
+        assert!(candidate.contains("r2 = true\n"));
+        assert_eq!("r0 = 1u8\nr1 = 2field\nr2 = true\n", candidate);
+        Ok(())
+    }
 }
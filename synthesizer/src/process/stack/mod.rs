@@ -198,6 +198,22 @@ impl<N: Network> Stack<N> {
         // Return the stack.
         Stack::initialize(process, program)
     }
+
+    /// Returns the closure with the given closure name.
+    #[inline]
+    pub fn get_closure(&self, closure_name: &Identifier<N>) -> Result<&Closure<N>> {
+        // Retrieve the closure, if it exists.
+        self.program
+            .closures()
+            .get(closure_name)
+            .ok_or_else(|| anyhow!("Closure '{closure_name}' does not exist in program '{}'.", self.program.id()))
+    }
+
+    /// Returns the names of all functions in the program, in order of declaration.
+    #[inline]
+    pub fn all_function_names(&self) -> Vec<&Identifier<N>> {
+        self.program.functions().keys().collect()
+    }
 }
 
 impl<N: Network> StackProgram<N> for Stack<N> {
@@ -311,6 +327,13 @@ impl<N: Network> Stack<N> {
         self.verifying_keys.read().contains_key(function_name)
     }
 
+    /// Returns `true` if the verifying keys for all functions in the program exist.
+    #[inline]
+    pub fn is_deployed(&self) -> bool {
+        let verifying_keys = self.verifying_keys.read();
+        self.program.functions().keys().all(|function_name| verifying_keys.contains_key(function_name))
+    }
+
     /// Returns the proving key for the given function name.
     #[inline]
     pub fn get_proving_key(&self, function_name: &Identifier<N>) -> Result<ProvingKey<N>> {
@@ -0,0 +1,44 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// Aggregate metrics about the programs loaded in a process.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct ProcessStatistics {
+    /// The number of loaded programs.
+    pub program_count: usize,
+    /// The total number of functions across the loaded programs.
+    pub total_function_count: usize,
+    /// The total number of mappings across the loaded programs.
+    pub total_mapping_count: usize,
+    /// The total number of functions with a loaded verifying key.
+    pub verifying_keys_loaded: usize,
+}
+
+impl<N: Network> Process<N> {
+    /// Returns aggregate metrics about the loaded programs.
+    #[inline]
+    pub fn statistics(&self) -> ProcessStatistics {
+        self.stacks.values().fold(ProcessStatistics::default(), |mut statistics, stack| {
+            let program = stack.program();
+            statistics.program_count += 1;
+            statistics.total_function_count += program.functions().len();
+            statistics.total_mapping_count += program.mappings().len();
+            statistics.verifying_keys_loaded +=
+                program.functions().keys().filter(|function_name| stack.contains_verifying_key(function_name)).count();
+            statistics
+        })
+    }
+}
@@ -1245,6 +1245,66 @@ function mint:
     assert_eq!(candidate, Value::from_str("100u64").unwrap());
 }
 
+#[test]
+fn test_process_authorize_call_transition_count() {
+    // Initialize a new program.
+    let (string, program0) = Program::<CurrentNetwork>::parse(
+        r"
+program child.aleo;
+
+function mint:
+    input r0 as address.public;
+    input r1 as u64.public;
+    output r1 as u64.public;",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Initialize another program, which calls the first program.
+    let (string, program1) = Program::<CurrentNetwork>::parse(
+        r"
+import child.aleo;
+
+program parent.aleo;
+
+function mint:
+    input r0 as address.public;
+    input r1 as u64.public;
+    call child.aleo/mint r0 r1 into r2;
+    output r2 as u64.public;",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Construct the process.
+    let mut process = Process::load().unwrap();
+    // Add the programs to the process.
+    process.add_program(&program0).unwrap();
+    process.add_program(&program1).unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Initialize caller.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let caller = Address::try_from(&caller_private_key).unwrap();
+
+    // Declare the function name.
+    let function_name = Identifier::from_str("mint").unwrap();
+
+    // Declare the input value.
+    let r0 = Value::<CurrentNetwork>::from_str(&caller.to_string()).unwrap();
+    let r1 = Value::<CurrentNetwork>::from_str("100u64").unwrap();
+
+    // Authorize the function call.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program1.id(), function_name, [r0, r1].iter(), rng)
+        .unwrap();
+    // Ensure the authorization produces one transition for the parent and one for the child.
+    assert_eq!(authorization.transition_count(), 2);
+    assert_eq!(authorization.transition_count(), authorization.len());
+}
+
 #[test]
 fn test_process_execute_and_finalize_get_set() {
     // Initialize a new program.
@@ -1845,6 +1905,456 @@ fn test_sanity_check_transfer_and_fee() {
     }
 }
 
+#[test]
+fn test_stack_get_closure() {
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program testing.aleo;
+
+closure twice:
+    input r0 as field;
+    add r0 r0 into r1;
+    output r1 as field;
+
+function compute:
+    input r0 as field.private;
+    call twice r0 into r1;
+    output r1 as field.private;",
+    )
+    .unwrap();
+
+    // Construct the stack.
+    let stack = Stack::new(&Process::load().unwrap(), &program).unwrap();
+
+    let closure_name = Identifier::from_str("twice").unwrap();
+    let function_name = Identifier::from_str("compute").unwrap();
+
+    // Ensure the closure is retrieved.
+    let closure = stack.get_closure(&closure_name).unwrap();
+    assert_eq!(&closure_name, closure.name());
+    assert_eq!(&program.get_closure(&closure_name).unwrap(), closure);
+    // Ensure the function is retrieved.
+    assert_eq!(&function_name, stack.get_function(&function_name).unwrap().name());
+
+    // Ensure a function is not retrieved as a closure, and vice versa.
+    assert!(stack.get_closure(&function_name).is_err());
+    assert!(stack.get_function(&closure_name).is_err());
+    // Ensure an undefined closure is not retrieved.
+    assert!(stack.get_closure(&Identifier::from_str("undefined").unwrap()).is_err());
+}
+
+#[test]
+fn test_stack_all_function_names() {
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program testing.aleo;
+
+closure twice:
+    input r0 as field;
+    add r0 r0 into r1;
+    output r1 as field;
+
+function first:
+    input r0 as field.private;
+    output r0 as field.private;
+
+function second:
+    input r0 as field.private;
+    call twice r0 into r1;
+    output r1 as field.private;",
+    )
+    .unwrap();
+
+    // Construct the stack.
+    let stack = Stack::new(&Process::load().unwrap(), &program).unwrap();
+
+    // Ensure both functions are returned, in order, without the closure.
+    let function_names = stack.all_function_names();
+    assert_eq!(2, function_names.len());
+    assert_eq!(&Identifier::from_str("first").unwrap(), function_names[0]);
+    assert_eq!(&Identifier::from_str("second").unwrap(), function_names[1]);
+    // Ensure there are no duplicates.
+    assert_eq!(function_names.len(), function_names.iter().collect::<std::collections::HashSet<_>>().len());
+}
+
+#[test]
+fn test_stack_is_deployed() {
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program testing.aleo;
+
+function first:
+    input r0 as field.private;
+    output r0 as field.private;
+
+function second:
+    input r0 as field.private;
+    add r0 r0 into r1;
+    output r1 as field.private;",
+    )
+    .unwrap();
+
+    // Construct the stack.
+    let process = Process::load().unwrap();
+    let stack = Stack::new(&process, &program).unwrap();
+    // Ensure the stack is not deployed after construction.
+    assert!(!stack.is_deployed());
+
+    // Retrieve a verifying key.
+    let verifying_key = process.get_verifying_key("credits.aleo", "transfer").unwrap();
+
+    // Ensure the stack is not deployed until all verifying keys are inserted.
+    stack.insert_verifying_key(&Identifier::from_str("first").unwrap(), verifying_key.clone()).unwrap();
+    assert!(!stack.is_deployed());
+    stack.insert_verifying_key(&Identifier::from_str("second").unwrap(), verifying_key).unwrap();
+    assert!(stack.is_deployed());
+
+    // Ensure the stack is not deployed after removing a verifying key.
+    stack.remove_verifying_key(&Identifier::from_str("first").unwrap());
+    assert!(!stack.is_deployed());
+}
+
+#[test]
+fn test_process_missing_verifying_keys() {
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program testing.aleo;
+
+function first:
+    input r0 as field.private;
+    output r0 as field.private;
+
+function second:
+    input r0 as field.private;
+    add r0 r0 into r1;
+    output r1 as field.private;",
+    )
+    .unwrap();
+
+    // Add the program to the process.
+    let mut process = Process::load().unwrap();
+    process.add_program(&program).unwrap();
+
+    let first = Identifier::from_str("first").unwrap();
+    let second = Identifier::from_str("second").unwrap();
+
+    // Ensure all functions are missing a verifying key.
+    assert_eq!(process.missing_verifying_keys(program.id()).unwrap(), vec![first, second]);
+
+    // Insert a verifying key for the first function only.
+    let verifying_key = process.get_verifying_key("credits.aleo", "transfer").unwrap();
+    process.insert_verifying_key(program.id(), &first, verifying_key).unwrap();
+
+    // Ensure only the second function is missing a verifying key.
+    assert_eq!(process.missing_verifying_keys(program.id()).unwrap(), vec![second]);
+    // Ensure the credits program is not missing any verifying keys.
+    assert!(process.missing_verifying_keys(&ProgramID::from_str("credits.aleo").unwrap()).unwrap().is_empty());
+    // Ensure an unknown program fails.
+    assert!(process.missing_verifying_keys(&ProgramID::from_str("unknown.aleo").unwrap()).is_err());
+}
+
+#[test]
+fn test_process_iter_program_ids() {
+    // Initialize a new process, which includes 'credits.aleo'.
+    let mut process = Process::<CurrentNetwork>::load().unwrap();
+
+    // Add two programs to the process.
+    for program_id in ["first.aleo", "second.aleo"] {
+        let program = Program::<CurrentNetwork>::from_str(&format!(
+            r"
+program {program_id};
+
+function compute:
+    input r0 as field.private;
+    output r0 as field.private;"
+        ))
+        .unwrap();
+        process.add_program(&program).unwrap();
+    }
+
+    // Ensure exactly three program IDs are yielded, in order of insertion.
+    let program_ids = process.iter_program_ids().map(|program_id| program_id.to_string()).collect::<Vec<_>>();
+    assert_eq!(vec!["credits.aleo", "first.aleo", "second.aleo"], program_ids);
+}
+
+#[test]
+fn test_process_reload_verifying_keys_from_dir() {
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program testing.aleo;
+
+function first:
+    input r0 as field.private;
+    add r0 r0 into r1;
+    output r1 as field.private;
+
+function second:
+    input r0 as field.private;
+    input r1 as field.private;
+    mul r0 r1 into r2;
+    output r2 as field.private;",
+    )
+    .unwrap();
+
+    // Construct the process.
+    let process = super::test_helpers::sample_process(&program);
+    // Synthesize the circuit keys for both functions.
+    let rng = &mut TestRng::default();
+    let first = Identifier::from_str("first").unwrap();
+    let second = Identifier::from_str("second").unwrap();
+    process.synthesize_key::<CurrentAleo, _>(program.id(), &first, rng).unwrap();
+    process.synthesize_key::<CurrentAleo, _>(program.id(), &second, rng).unwrap();
+
+    let first_verifying_key = process.get_verifying_key(program.id(), first).unwrap();
+    let second_verifying_key = process.get_verifying_key(program.id(), second).unwrap();
+    assert_ne!(first_verifying_key, second_verifying_key);
+
+    // Write the verifying key of 'first' as the verifying key of 'second'.
+    let directory = tempfile::tempdir().unwrap();
+    std::fs::write(directory.path().join("testing.aleo_second.vk"), first_verifying_key.to_bytes_le().unwrap())
+        .unwrap();
+    // Write an unrelated file, which should be ignored.
+    std::fs::write(directory.path().join("README.md"), "ignored").unwrap();
+
+    // Reload the verifying keys.
+    process.reload_verifying_keys_from_dir(directory.path()).unwrap();
+    // Ensure the verifying key of 'second' is updated, and 'first' is unchanged.
+    assert_eq!(first_verifying_key, process.get_verifying_key(program.id(), second).unwrap());
+    assert_eq!(first_verifying_key, process.get_verifying_key(program.id(), first).unwrap());
+
+    // Ensure a verifying key for an unknown program fails, and no verifying key is updated.
+    std::fs::write(directory.path().join("testing.aleo_first.vk"), second_verifying_key.to_bytes_le().unwrap())
+        .unwrap();
+    std::fs::write(directory.path().join("unknown.aleo_first.vk"), first_verifying_key.to_bytes_le().unwrap()).unwrap();
+    assert!(process.reload_verifying_keys_from_dir(directory.path()).is_err());
+    assert_eq!(first_verifying_key, process.get_verifying_key(program.id(), first).unwrap());
+    std::fs::remove_file(directory.path().join("unknown.aleo_first.vk")).unwrap();
+    // Ensure a verifying key for an unknown function fails, and no verifying key is updated.
+    std::fs::write(directory.path().join("testing.aleo_third.vk"), first_verifying_key.to_bytes_le().unwrap()).unwrap();
+    assert!(process.reload_verifying_keys_from_dir(directory.path()).is_err());
+    assert_eq!(first_verifying_key, process.get_verifying_key(program.id(), first).unwrap());
+    // Ensure a missing directory fails.
+    assert!(process.reload_verifying_keys_from_dir(&directory.path().join("missing")).is_err());
+}
+
+#[test]
+fn test_process_export_and_import_state() {
+    // Initialize the programs.
+    let child = Program::<CurrentNetwork>::from_str(
+        r"
+program child.aleo;
+
+function compute:
+    input r0 as u32.private;
+    add r0 r0 into r1;
+    output r1 as u32.private;",
+    )
+    .unwrap();
+    let parent = Program::<CurrentNetwork>::from_str(
+        r"
+import child.aleo;
+
+program parent.aleo;
+
+function compute:
+    input r0 as u32.private;
+    call child.aleo/compute r0 into r1;
+    output r1 as u32.private;",
+    )
+    .unwrap();
+
+    // Construct the process, with 'credits.aleo', 'child.aleo', and 'parent.aleo'.
+    let mut process = Process::<CurrentNetwork>::load().unwrap();
+    process.add_program(&child).unwrap();
+    process.add_program(&parent).unwrap();
+    assert_eq!(3, process.iter_program_ids().count());
+
+    // Synthesize the circuit keys for 'child.aleo'.
+    let rng = &mut TestRng::default();
+    let function_name = Identifier::from_str("compute").unwrap();
+    process.synthesize_key::<CurrentAleo, _>(child.id(), &function_name, rng).unwrap();
+    let verifying_key = process.get_verifying_key(child.id(), function_name).unwrap();
+
+    // Export the state.
+    let directory = tempfile::tempdir().unwrap();
+    process.export_state(directory.path()).unwrap();
+
+    // Import the state into a new process.
+    let mut candidate = Process::<CurrentNetwork>::load().unwrap();
+    candidate.import_state(directory.path()).unwrap();
+
+    // Ensure the programs and verifying keys are restored.
+    assert_eq!(process.iter_program_ids().collect::<Vec<_>>(), candidate.iter_program_ids().collect::<Vec<_>>());
+    assert_eq!(&child, candidate.get_program(child.id()).unwrap());
+    assert_eq!(&parent, candidate.get_program(parent.id()).unwrap());
+    assert_eq!(verifying_key, candidate.get_verifying_key(child.id(), function_name).unwrap());
+
+    // Ensure the imported functions are callable.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let authorization = candidate
+        .authorize::<CurrentAleo, _>(
+            &caller_private_key,
+            parent.id(),
+            function_name,
+            [Value::<CurrentNetwork>::from_str("3u32").unwrap()].iter(),
+            rng,
+        )
+        .unwrap();
+    let response = candidate.evaluate::<CurrentAleo>(authorization).unwrap();
+    assert_eq!(vec![Value::from_str("6u32").unwrap()], response.outputs().to_vec());
+
+    // Construct a snapshot with 'child.aleo', and a program with a missing import.
+    let orphan = Program::<CurrentNetwork>::from_str(
+        r"
+import missing.aleo;
+
+program orphan.aleo;
+
+function compute:
+    input r0 as u32.private;
+    output r0 as u32.private;",
+    )
+    .unwrap();
+    let partial = tempfile::tempdir().unwrap();
+    std::fs::copy(directory.path().join("child.aleo.snapshot"), partial.path().join("child.aleo.snapshot")).unwrap();
+    let mut bytes = orphan.to_bytes_le().unwrap();
+    bytes.extend(Deployment::<CurrentNetwork>::write_verifying_keys_bundle(std::iter::empty()).unwrap());
+    std::fs::write(partial.path().join("orphan.aleo.snapshot"), bytes).unwrap();
+
+    // Ensure the import fails, and the process is unchanged.
+    let mut candidate = Process::<CurrentNetwork>::load().unwrap();
+    assert!(candidate.import_state(partial.path()).is_err());
+    assert!(!candidate.contains_program(child.id()));
+    assert_eq!(1, candidate.iter_program_ids().count());
+}
+
+#[test]
+fn test_process_compile_credits_program() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the credits program.
+    let program = Program::<CurrentNetwork>::credits().unwrap();
+    // Construct the process.
+    let process = Process::<CurrentNetwork>::load().unwrap();
+
+    // Compile the program.
+    let keys = process.compile::<CurrentAleo, _>(&program, rng).unwrap();
+    assert_eq!(keys.len(), program.functions().len());
+
+    // Ensure each function has a non-empty proving key.
+    for ((function_name, proving_key, verifying_key), expected_name) in keys.iter().zip_eq(program.functions().keys()) {
+        assert_eq!(function_name, expected_name);
+        assert!(!proving_key.to_bytes_le().unwrap().is_empty());
+        assert!(!verifying_key.to_bytes_le().unwrap().is_empty());
+    }
+}
+
+#[test]
+fn test_process_verify_deployment_fee() {
+    let rng = &mut TestRng::default();
+
+    // Sample a deployment transaction.
+    let transaction = crate::vm::test_helpers::sample_deployment_transaction(rng);
+    let (deployment, fee) = match &transaction {
+        crate::Transaction::Deploy(_, _, deployment, fee) => (deployment, fee),
+        _ => panic!("Expected a deployment transaction"),
+    };
+
+    // Construct the process.
+    let process = Process::<CurrentNetwork>::load().unwrap();
+
+    // Ensure the attached fee covers the deployment.
+    process.verify_deployment_fee(deployment, fee).unwrap();
+
+    // Ensure an under-payment fails with the shortfall amount.
+    let underpayment = crate::vm::test_helpers::sample_fee(rng);
+    let deployment_cost = deployment.size_in_bytes().unwrap() * CurrentNetwork::DEPLOYMENT_FEE_MULTIPLIER;
+    let shortfall = deployment_cost - *underpayment.amount().unwrap();
+    let error = process.verify_deployment_fee(deployment, &underpayment).unwrap_err();
+    assert!(error.to_string().contains(&format!("short by {shortfall} microcredits")));
+}
+
+#[test]
+fn test_process_validate_program() {
+    // Initialize the imported program.
+    let token = Program::<CurrentNetwork>::from_str(
+        r"
+program token.aleo;
+
+function noop:
+    input r0 as u64.private;
+    output r0 as u64.private;",
+    )
+    .unwrap();
+
+    // Initialize a program that imports the token program.
+    let wallet = Program::<CurrentNetwork>::from_str(
+        r"
+import token.aleo;
+
+program wallet.aleo;
+
+function forward:
+    input r0 as u64.private;
+    call token.aleo/noop r0 into r1;
+    output r1 as u64.private;",
+    )
+    .unwrap();
+
+    // Ensure the programs are valid, given their imports.
+    Process::validate_program(&token, &[]).unwrap();
+    Process::validate_program(&wallet, std::slice::from_ref(&token)).unwrap();
+
+    // Ensure an unresolved import fails.
+    let error = Process::validate_program(&wallet, &[]).unwrap_err();
+    assert!(error.to_string().contains("imports 'token.aleo', which is not among the validated programs"));
+
+    // Ensure an invalid import fails.
+    let error = Process::validate_program(&wallet, &[token.clone(), token]).unwrap_err();
+    assert!(error.to_string().starts_with("Failed to load the import 'token.aleo' of program 'wallet.aleo'"));
+
+    // Ensure an ill-typed function fails.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program typo.aleo;
+
+function compute:
+    input r0 as u64.private;
+    input r1 as field.private;
+    add r0 r1 into r2;
+    output r2 as u64.private;",
+    )
+    .unwrap();
+    let error = Process::validate_program(&program, &[]).unwrap_err();
+    assert!(error.to_string().starts_with("Program 'typo.aleo' is invalid"));
+
+    // Ensure an ill-typed finalize block fails.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program counter.aleo;
+
+mapping account:
+    key owner as address.public;
+    value amount as u64.public;
+
+function compute:
+    input r0 as u64.public;
+    finalize r0;
+
+finalize compute:
+    input r0 as u64.public;
+    get.or_use account[r0] 0u64 into r1;",
+    )
+    .unwrap();
+    let error = Process::validate_program(&program, &[]).unwrap_err();
+    assert!(error.to_string().starts_with("Program 'counter.aleo' is invalid"));
+}
+
 fn get_assignment(
     stack: &Stack<CurrentNetwork>,
     private_key: &PrivateKey<CurrentNetwork>,
@@ -1868,3 +2378,243 @@ fn get_assignment(
     let assignment = assignments.read().last().unwrap().clone();
     assignment
 }
+
+#[ignore]
+#[test]
+fn test_derive_genesis_verifying_keys() {
+    let rng = &mut TestRng::default();
+
+    // Synthesize the genesis verifying keys.
+    let verifying_keys = Process::<CurrentNetwork>::derive_genesis_verifying_keys::<CurrentAleo, _>(rng).unwrap();
+
+    // Ensure every 'credits.aleo' function has a key, matching the cached verifying key.
+    let program = Program::<CurrentNetwork>::credits().unwrap();
+    assert_eq!(verifying_keys.len(), program.functions().len());
+    for function_name in program.functions().keys() {
+        let expected = CurrentNetwork::get_credits_verifying_key(function_name.to_string()).unwrap();
+        assert_eq!(verifying_keys[function_name], VerifyingKey::new(expected.clone()));
+    }
+}
+
+#[test]
+fn test_process_statistics() {
+    // Initialize a program with two functions and one mapping.
+    let token = Program::<CurrentNetwork>::from_str(
+        r"
+program token.aleo;
+
+mapping account:
+    key owner as address.public;
+    value amount as u64.public;
+
+function noop:
+    input r0 as u64.private;
+    output r0 as u64.private;
+
+function compute:
+    input r0 as u64.private;
+    add r0 r0 into r1;
+    output r1 as u64.private;",
+    )
+    .unwrap();
+
+    // Initialize a program with one function and two mappings.
+    let registry = Program::<CurrentNetwork>::from_str(
+        r"
+program registry.aleo;
+
+mapping names:
+    key owner as address.public;
+    value name as field.public;
+
+mapping counts:
+    key owner as address.public;
+    value count as u64.public;
+
+function noop:
+    input r0 as field.private;
+    output r0 as field.private;",
+    )
+    .unwrap();
+
+    // Initialize an empty process.
+    let mut process =
+        Process::<CurrentNetwork> { universal_srs: Arc::new(UniversalSRS::load().unwrap()), stacks: IndexMap::new() };
+    assert_eq!(process.statistics(), ProcessStatistics::default());
+
+    // Add the programs, and insert one verifying key.
+    process.add_program(&token).unwrap();
+    process.add_program(&registry).unwrap();
+    let verifying_key = CurrentNetwork::get_credits_verifying_key("transfer".to_string()).unwrap();
+    process
+        .get_stack(token.id())
+        .unwrap()
+        .insert_verifying_key(&Identifier::from_str("noop").unwrap(), VerifyingKey::new(verifying_key.clone()))
+        .unwrap();
+
+    // Ensure the statistics are correct.
+    let statistics = process.statistics();
+    assert_eq!(statistics.program_count, 2);
+    assert_eq!(statistics.total_function_count, 3);
+    assert_eq!(statistics.total_mapping_count, 3);
+    assert_eq!(statistics.verifying_keys_loaded, 1);
+}
+
+#[test]
+fn test_stack_verify_function_inputs() {
+    let rng = &mut TestRng::default();
+
+    // Initialize a program with public, private, and record inputs.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program token.aleo;
+
+record token:
+    owner as address.private;
+    amount as u64.private;
+
+function transfer:
+    input r0 as u64.public;
+    input r1 as field.private;
+    input r2 as token.record;
+    cast r2.owner r2.amount into r3 as token.record;
+    output r3 as token.record;",
+    )
+    .unwrap();
+
+    // Initialize an empty process, and add the program.
+    let mut process =
+        Process::<CurrentNetwork> { universal_srs: Arc::new(UniversalSRS::load().unwrap()), stacks: IndexMap::new() };
+    process.add_program(&program).unwrap();
+    let stack = process.get_stack(program.id()).unwrap();
+    let function_name = Identifier::from_str("transfer").unwrap();
+
+    // Prepare the inputs.
+    let caller = Address::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+    let amount = Value::<CurrentNetwork>::from_str("100u64").unwrap();
+    let field = Value::<CurrentNetwork>::from_str("1field").unwrap();
+    let record = Value::<CurrentNetwork>::from_str(&format!(
+        "{{ owner: {caller}.private, amount: 100u64.private, _nonce: 0group.public }}"
+    ))
+    .unwrap();
+
+    // Ensure valid inputs pass.
+    stack.verify_function_inputs(&function_name, &[amount.clone(), field.clone(), record.clone()]).unwrap();
+
+    // Ensure the wrong number of inputs fails.
+    assert!(stack.verify_function_inputs(&function_name, &[amount.clone(), field.clone()]).is_err());
+
+    // Ensure an input of the wrong type fails, reporting its position and type.
+    let wrong_type = Value::<CurrentNetwork>::from_str("100u32").unwrap();
+    let error = stack.verify_function_inputs(&function_name, &[wrong_type, field.clone(), record.clone()]).unwrap_err();
+    let expected = "Input 0 of function 'transfer' in program 'token.aleo' does not match 'u64.public'";
+    assert!(error.to_string().starts_with(expected), "{error}");
+
+    // Ensure a record passed as a public input fails.
+    let error = stack.verify_function_inputs(&function_name, &[record.clone(), field.clone(), record]).unwrap_err();
+    assert!(error.to_string().starts_with("Input 0 of function 'transfer'"));
+
+    // Ensure a plaintext passed as a record input fails.
+    let error = stack.verify_function_inputs(&function_name, &[amount, field.clone(), field]).unwrap_err();
+    let expected = "Input 2 of function 'transfer' in program 'token.aleo' does not match 'token.record'";
+    assert!(error.to_string().starts_with(expected), "{error}");
+}
+
+#[test]
+fn test_finalize_simulate() {
+    let rng = &mut TestRng::default();
+
+    // Initialize a program with a finalize scope.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program counter.aleo;
+
+mapping account:
+    key owner as address.public;
+    value amount as u64.public;
+
+function compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    finalize r0 r1;
+
+finalize compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    get.or_use account[r0] 0u64 into r2;
+    add r2 r1 into r3;
+    set r3 into account[r0];
+",
+    )
+    .unwrap();
+
+    // Initialize an empty process, and add the program.
+    let mut process =
+        Process::<CurrentNetwork> { universal_srs: Arc::new(UniversalSRS::load().unwrap()), stacks: IndexMap::new() };
+    process.add_program(&program).unwrap();
+    let stack = process.get_stack(program.id()).unwrap();
+    let function = program.get_function(&Identifier::from_str("compute").unwrap()).unwrap();
+    let (_, finalize) = function.finalize().unwrap();
+
+    // Initialize the finalize store, and the mapping.
+    let store = FinalizeStore::<CurrentNetwork, FinalizeMemory<CurrentNetwork>>::open(None).unwrap();
+    let mapping_name = Identifier::from_str("account").unwrap();
+    store.initialize_mapping(program.id(), &mapping_name).unwrap();
+
+    // Prepare the inputs.
+    let caller = Address::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+    let key = Plaintext::from(Literal::Address(caller));
+    let inputs = [key.clone(), Plaintext::from_str("5u64").unwrap()];
+
+    // Simulate the finalize scope.
+    let (operations, registers) = finalize.simulate(stack, &store, FinalizeGlobalState::new(0), &inputs).unwrap();
+    assert_eq!(operations.len(), 1);
+    let r3 = crate::Operand::Register(console::program::Register::Locator(3));
+    assert_eq!(registers.load_literal(stack, &r3).unwrap(), Literal::from_str("5u64").unwrap());
+    // Ensure the store is unchanged.
+    assert!(store.get_value_speculative(program.id(), &mapping_name, &key).unwrap().is_none());
+
+    // Commit the finalize scope, and ensure the operations match the simulation.
+    let value = Value::from_str("5u64").unwrap();
+    let expected = store.update_key_value(program.id(), &mapping_name, key.clone(), value.clone()).unwrap();
+    assert_eq!(operations, vec![expected]);
+
+    // Ensure the simulation reads the committed state, and still leaves it unchanged.
+    let (operations, _) = finalize.simulate(stack, &store, FinalizeGlobalState::new(0), &inputs).unwrap();
+    assert_eq!(operations.len(), 1);
+    assert_eq!(store.get_value_speculative(program.id(), &mapping_name, &key).unwrap(), Some(value.clone()));
+
+    // Ensure the simulation does not interfere with an atomic batch in progress on the store.
+    store.start_atomic();
+    store.update_key_value(program.id(), &mapping_name, key.clone(), Value::from_str("7u64").unwrap()).unwrap();
+    let (simulated, _) = finalize.simulate(stack, &store, FinalizeGlobalState::new(0), &inputs).unwrap();
+    assert_eq!(simulated, operations);
+    assert!(store.is_atomic_in_progress());
+    store.abort_atomic();
+    assert_eq!(store.get_value_speculative(program.id(), &mapping_name, &key).unwrap(), Some(value));
+}
+
+#[test]
+fn test_remove_dead_registers_passes_stack() {
+    // Initialize a function with a dead write in the middle, and a trailing dead write.
+    let mut function = Function::<CurrentNetwork>::from_str(
+        r"
+function compute:
+    input r0 as u32.private;
+    add.w r0 r0 into r1;
+    add.w r0 r0 into r2;
+    mul.w r2 r2 into r3;
+    output r2 as u32.public;",
+    )
+    .unwrap();
+
+    // Ensure only the trailing dead write is removed.
+    assert_eq!(function.remove_dead_registers(), 1);
+    assert_eq!(function.instructions().len(), 2);
+
+    // Ensure the resulting function is accepted by the stack.
+    let program = Program::<CurrentNetwork>::from_str(&format!("program testing.aleo;\n\n{function}")).unwrap();
+    let process =
+        Process::<CurrentNetwork> { universal_srs: Arc::new(UniversalSRS::load().unwrap()), stacks: IndexMap::new() };
+    Stack::new(&process, &program).unwrap();
+}
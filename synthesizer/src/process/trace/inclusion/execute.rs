@@ -79,6 +79,7 @@ macro_rules! prepare_execution_impl {
 
                         // Construct the assignment for the state path.
                         let assignment = InclusionAssignment::new(
+                            *transition.id(),
                             state_path,
                             task.commitment,
                             task.gamma,
@@ -53,6 +53,7 @@ macro_rules! prepare_fee_impl {
 
                     // Prepare the assignment for the state path.
                     let assignment = InclusionAssignment::new(
+                        *$fee_transition.id(),
                         state_path,
                         task.commitment,
                         task.gamma,
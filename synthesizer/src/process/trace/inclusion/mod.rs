@@ -58,6 +58,17 @@ impl<N: Network> Inclusion<N> {
         Self { input_tasks: HashMap::new(), output_commitments: HashMap::new() }
     }
 
+    /// Returns the transition ID and output index of the local record with the given commitment, if it exists.
+    /// Note: The output index is the position of the output in the transition tree (i.e. after the inputs).
+    pub fn output_commitment_transition(&self, commitment: &Field<N>) -> Option<(N::TransitionID, u8)> {
+        self.output_commitments.get(commitment).copied()
+    }
+
+    /// Returns the number of inclusion proofs to be generated, which is the number of input tasks.
+    pub fn pending_proof_count(&self) -> usize {
+        self.input_tasks.values().map(Vec::len).sum()
+    }
+
     /// Inserts the transition to build state for the inclusion task.
     pub fn insert_transition(&mut self, input_ids: &[InputID<N>], transition: &Transition<N>) -> Result<()> {
         // Ensure the transition inputs and input IDs are the same length.
@@ -149,6 +160,8 @@ impl<N: Network> Inclusion<N> {
 
 #[derive(Clone, Debug)]
 pub struct InclusionAssignment<N: Network> {
+    /// The ID of the transition that consumes the input record.
+    pub transition_id: N::TransitionID,
     pub(crate) state_path: StatePath<N>,
     commitment: Field<N>,
     gamma: Group<N>,
@@ -160,6 +173,7 @@ pub struct InclusionAssignment<N: Network> {
 impl<N: Network> InclusionAssignment<N> {
     /// Initializes a new inclusion assignment.
     pub fn new(
+        transition_id: N::TransitionID,
         state_path: StatePath<N>,
         commitment: Field<N>,
         gamma: Group<N>,
@@ -167,7 +181,22 @@ impl<N: Network> InclusionAssignment<N> {
         local_state_root: N::TransactionID,
         is_global: bool,
     ) -> Self {
-        Self { state_path, commitment, gamma, serial_number, local_state_root, is_global }
+        Self { transition_id, state_path, commitment, gamma, serial_number, local_state_root, is_global }
+    }
+
+    /// Returns the local state root.
+    pub const fn local_state_root(&self) -> &N::TransactionID {
+        &self.local_state_root
+    }
+
+    /// Returns `true` if the assignment proves inclusion in the global state root.
+    pub const fn is_global_state_proof(&self) -> bool {
+        self.is_global
+    }
+
+    /// Returns `true` if the assignment proves inclusion in the local state root.
+    pub const fn is_local_state_proof(&self) -> bool {
+        !self.is_global
     }
 
     /// The circuit for state path verification.
@@ -222,3 +251,222 @@ impl<N: Network> InclusionAssignment<N> {
         Ok(A::eject_assignment_and_reset())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        process::{Process, Trace},
+        program::Program,
+        store::{helpers::memory::BlockMemory, BlockStore},
+    };
+    use circuit::network::AleoV0;
+    use console::{
+        account::{Address, PrivateKey},
+        network::Testnet3,
+        program::{
+            state_path::test_helpers::{sample_global_state_path, sample_local_state_path},
+            Value,
+        },
+    };
+
+    use once_cell::sync::OnceCell;
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = AleoV0;
+
+    /// Returns a prepared trace, where each of the two `token.aleo/burn` transitions consumes a local record.
+    pub(crate) fn sample_trace() -> Trace<CurrentNetwork> {
+        // Initialize the token program.
+        let program0 = Program::<CurrentNetwork>::from_str(
+            r"
+program token.aleo;
+
+record token:
+    owner as address.private;
+    amount as u64.private;
+
+function mint:
+    input r0 as address.private;
+    input r1 as u64.private;
+    cast r0 r1 into r2 as token.record;
+    output r2 as token.record;
+
+function burn:
+    input r0 as token.record;",
+        )
+        .unwrap();
+        // Initialize the wallet program.
+        let program1 = Program::<CurrentNetwork>::from_str(
+            r"
+import token.aleo;
+
+program wallet.aleo;
+
+function mint_and_burn:
+    input r0 as address.private;
+    input r1 as u64.private;
+    call token.aleo/mint r0 r1 into r2;
+    call token.aleo/mint r0 r1 into r3;
+    call token.aleo/burn r2;
+    call token.aleo/burn r3;",
+        )
+        .unwrap();
+
+        // Construct the process.
+        let mut process = Process::load().unwrap();
+        process.add_program(&program0).unwrap();
+        process.add_program(&program1).unwrap();
+
+        // Initialize the RNG.
+        let rng = &mut TestRng::default();
+
+        // Initialize the caller.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let caller = Address::try_from(&caller_private_key).unwrap();
+
+        // Declare the inputs.
+        let r0 = Value::<CurrentNetwork>::from_str(&caller.to_string()).unwrap();
+        let r1 = Value::<CurrentNetwork>::from_str("100u64").unwrap();
+
+        // Authorize the function call.
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program1.id(), "mint_and_burn", [r0, r1].iter(), rng)
+            .unwrap();
+        assert_eq!(authorization.len(), 5);
+
+        // Execute the request.
+        let (_response, mut trace) = process.execute::<CurrentAleo>(authorization).unwrap();
+
+        // Prepare the trace.
+        let block_store = BlockStore::<_, BlockMemory<_>>::open(None).unwrap();
+        trace.prepare(block_store).unwrap();
+        trace
+    }
+
+    #[test]
+    fn test_pending_proof_count() {
+        // Initializes an input task for a record.
+        let task = || InputTask::<CurrentNetwork> {
+            commitment: Field::zero(),
+            gamma: Group::zero(),
+            serial_number: Field::zero(),
+            leaf: TransitionLeaf::new_with_version(0, 3, Field::zero()),
+            is_local: false,
+        };
+
+        // Ensure an empty inclusion has no pending proofs.
+        let mut inclusion = Inclusion::<CurrentNetwork>::new();
+        assert_eq!(inclusion.pending_proof_count(), 0);
+
+        // Insert three transitions with 2, 1, and 0 record inputs.
+        for (id, num_records) in [(1u64, 2), (2, 1), (3, 0)] {
+            let tasks = (0..num_records).map(|_| task()).collect();
+            inclusion.input_tasks.insert(Field::<CurrentNetwork>::from_u64(id).into(), tasks);
+        }
+        assert_eq!(inclusion.pending_proof_count(), 3);
+    }
+
+    #[test]
+    fn test_state_proof_predicates() {
+        let rng = &mut TestRng::default();
+
+        // Initializes an assignment with the given state path and flag.
+        let assignment = |state_path: StatePath<CurrentNetwork>, is_global| {
+            let local_state_root = *state_path.transaction_id();
+            InclusionAssignment::new(
+                Default::default(),
+                state_path,
+                Field::zero(),
+                Group::zero(),
+                Field::zero(),
+                local_state_root,
+                is_global,
+            )
+        };
+
+        // Ensure a global state proof is reported as such.
+        let state_path = sample_global_state_path::<CurrentNetwork>(None, rng).unwrap();
+        let global = assignment(state_path.clone(), true);
+        assert!(global.is_global_state_proof());
+        assert!(!global.is_local_state_proof());
+        assert_eq!(global.local_state_root(), state_path.transaction_id());
+
+        // Ensure a local state proof is reported as such.
+        let state_path = sample_local_state_path::<CurrentNetwork>(None, rng).unwrap();
+        let local = assignment(state_path.clone(), false);
+        assert!(!local.is_global_state_proof());
+        assert!(local.is_local_state_proof());
+        assert_eq!(local.local_state_root(), state_path.transaction_id());
+    }
+
+    #[test]
+    fn test_assignment_transition_id() {
+        let trace = sample_trace();
+
+        // Retrieve the inclusion assignments.
+        let assignments = trace.inclusion_assignments.get().unwrap();
+        assert_eq!(assignments.len(), 2);
+
+        for assignment in assignments {
+            // Retrieve the transition that consumes the record.
+            let transition = trace
+                .transitions()
+                .iter()
+                .find(|transition| transition.serial_numbers().contains(&assignment.serial_number))
+                .unwrap();
+            // Ensure the transition ID matches.
+            assert_eq!(assignment.transition_id, *transition.id());
+            // Ensure the transition ID matches the input task.
+            let tasks = &trace.inclusion_tasks.input_tasks[transition.id()];
+            assert!(tasks.iter().any(|task| task.serial_number == assignment.serial_number));
+        }
+    }
+
+    #[test]
+    fn test_assignments_for_transition() {
+        let trace = sample_trace();
+
+        // Ensure each assignment belongs to the queried transition.
+        let mut num_assignments = 0;
+        for transition in trace.transitions() {
+            let assignments = trace.inclusion_assignments_for_transition(transition.id()).unwrap();
+            // Ensure there is one assignment for each record input.
+            assert_eq!(assignments.len(), transition.serial_numbers().count());
+            for assignment in &assignments {
+                assert_eq!(assignment.transition_id, *transition.id());
+                assert!(transition.serial_numbers().contains(&assignment.serial_number));
+            }
+            num_assignments += assignments.len();
+        }
+        assert_eq!(num_assignments, 2);
+
+        // Ensure an unknown transition ID fails.
+        assert!(trace.inclusion_assignments_for_transition(&Default::default()).is_err());
+        // Ensure an unprepared trace fails.
+        let unprepared = Trace { inclusion_assignments: OnceCell::new(), ..trace.clone() };
+        assert!(unprepared.inclusion_assignments_for_transition(trace.transitions()[0].id()).is_err());
+    }
+
+    #[test]
+    fn test_output_commitment_transition() {
+        let trace = sample_trace();
+        let inclusion = &trace.inclusion_tasks;
+
+        // Ensure each record output maps to the transition that produced it.
+        let mut num_records = 0;
+        for transition in trace.transitions() {
+            for (index, output) in transition.outputs().iter().enumerate() {
+                if let Some(commitment) = output.commitment() {
+                    let expected = (*transition.id(), (transition.inputs().len() + index) as u8);
+                    assert_eq!(inclusion.output_commitment_transition(commitment), Some(expected));
+                    num_records += 1;
+                }
+            }
+        }
+        assert_eq!(num_records, 2);
+
+        // Ensure an unknown commitment is not found.
+        assert_eq!(inclusion.output_commitment_transition(&Field::zero()), None);
+    }
+}
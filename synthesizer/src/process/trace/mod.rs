@@ -30,6 +30,7 @@ use circuit::Assignment;
 use console::{
     network::prelude::*,
     program::{InputID, Locator},
+    types::Field,
 };
 
 use once_cell::sync::OnceCell;
@@ -74,6 +75,27 @@ impl<N: Network> Trace<N> {
     pub fn call_metrics(&self) -> &[CallMetrics<N>] {
         &self.call_metrics
     }
+
+    /// Returns the inclusion assignments for the given transition ID.
+    pub fn inclusion_assignments_for_transition(&self, id: &N::TransitionID) -> Result<Vec<InclusionAssignment<N>>> {
+        // Ensure the transition exists.
+        ensure!(self.transitions.iter().any(|transition| transition.id() == id), "Transition '{id}' is not in the trace");
+        // Retrieve the inclusion assignments.
+        let inclusion_assignments =
+            self.inclusion_assignments.get().ok_or_else(|| anyhow!("Inclusion assignments have not been set"))?;
+        // Return the inclusion assignments for the transition.
+        Ok(inclusion_assignments.iter().filter(|assignment| &assignment.transition_id == id).cloned().collect())
+    }
+
+    /// Returns the transition ID and output index of the local record with the given commitment, if it exists.
+    pub fn output_commitment_transition(&self, commitment: &Field<N>) -> Option<(N::TransitionID, u8)> {
+        self.inclusion_tasks.output_commitment_transition(commitment)
+    }
+
+    /// Returns the number of inclusion proofs to be generated for this trace.
+    pub fn pending_inclusion_proof_count(&self) -> usize {
+        self.inclusion_tasks.pending_proof_count()
+    }
 }
 
 impl<N: Network> Trace<N> {
@@ -19,6 +19,14 @@ use console::{
     types::Field,
 };
 
+use std::collections::HashMap;
+
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
+mod prefetched;
+pub use prefetched::*;
+
 #[derive(Clone)]
 pub enum Query<N: Network, B: BlockStorage<N>> {
     /// The block store from the VM.
@@ -130,6 +138,16 @@ impl<N: Network, B: BlockStorage<N>> Query<N, B> {
         }
     }
 
+    /// Returns the state paths for the given `commitments`, fetching them in parallel.
+    ///
+    /// Note: The state paths are computed against the current state root, and become stale once it advances.
+    /// To serve them to later lookups, use `PrefetchedQuery`.
+    pub fn prefetch_state_paths(&self, commitments: &[Field<N>]) -> Result<HashMap<Field<N>, StatePath<N>>> {
+        cfg_iter!(commitments)
+            .map(|commitment| Ok((*commitment, self.get_state_path_for_commitment(commitment)?)))
+            .collect::<Result<HashMap<_, _>>>()
+    }
+
     /// Performs a GET request to the given URL.
     fn get_request(url: &str) -> Result<ureq::Response> {
         let response = ureq::get(url).call()?;
@@ -142,3 +160,40 @@ impl<N: Network, B: BlockStorage<N>> Query<N, B> {
         if response.status() == 200 { Ok(response) } else { bail!("Failed to fetch from {url}") }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        store::helpers::memory::BlockMemory,
+        vm::test_helpers::{sample_genesis_block, sample_vm_with_genesis_block},
+    };
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_prefetch_state_paths() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the VM with the genesis block.
+        let vm = sample_vm_with_genesis_block(rng);
+        let query = Query::<CurrentNetwork, BlockMemory<CurrentNetwork>>::from(vm.block_store());
+
+        // Fetch the commitments of the genesis block.
+        let commitments = sample_genesis_block(rng).into_commitments().collect::<Vec<_>>();
+        assert!(!commitments.is_empty());
+
+        // Prefetch the state paths.
+        let state_paths = query.prefetch_state_paths(&commitments).unwrap();
+        assert_eq!(state_paths.len(), commitments.len());
+
+        // Ensure the state paths match the individual lookups.
+        for commitment in &commitments {
+            assert_eq!(state_paths[commitment], query.get_state_path_for_commitment(commitment).unwrap());
+        }
+
+        // Ensure an unknown commitment is rejected.
+        assert!(query.prefetch_state_paths(&[Field::rand(rng)]).is_err());
+    }
+}
@@ -0,0 +1,122 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A query that serves the state paths prefetched for a fixed global state root,
+/// and defers all other lookups to the underlying query.
+#[derive(Clone)]
+pub struct PrefetchedQuery<N: Network, B: BlockStorage<N>> {
+    /// The underlying query.
+    query: Query<N, B>,
+    /// The global state root of the prefetched state paths.
+    state_root: N::StateRoot,
+    /// The prefetched state paths.
+    state_paths: HashMap<Field<N>, StatePath<N>>,
+}
+
+impl<N: Network, B: BlockStorage<N>> PrefetchedQuery<N, B> {
+    /// Initializes a new prefetched query, by prefetching the state paths for the given `commitments`.
+    pub fn new(query: Query<N, B>, commitments: &[Field<N>]) -> Result<Self> {
+        // Retrieve the current state root.
+        let state_root = query.current_state_root()?;
+        // Prefetch the state paths.
+        let state_paths = query.prefetch_state_paths(commitments)?;
+        // Ensure the state paths were all computed against the same state root.
+        for state_path in state_paths.values() {
+            ensure!(
+                state_path.global_state_root() == state_root,
+                "The state root advanced while prefetching the state paths"
+            );
+        }
+        Ok(Self { query, state_root, state_paths })
+    }
+
+    /// Returns the underlying query.
+    pub const fn query(&self) -> &Query<N, B> {
+        &self.query
+    }
+
+    /// Returns the global state root of the prefetched state paths.
+    /// Note: This is the state root at the time of prefetching, and is not refreshed.
+    pub const fn current_state_root(&self) -> N::StateRoot {
+        self.state_root
+    }
+
+    /// Returns a state path for the given `commitment`.
+    /// If the state path was not prefetched, it is fetched from the underlying query, and must be
+    /// for the same state root. Otherwise, the prefetched state paths are stale, and an error is returned.
+    pub fn get_state_path_for_commitment(&self, commitment: &Field<N>) -> Result<StatePath<N>> {
+        // Return the prefetched state path, if it exists.
+        if let Some(state_path) = self.state_paths.get(commitment) {
+            return Ok(state_path.clone());
+        }
+        // Fetch the state path.
+        let state_path = self.query.get_state_path_for_commitment(commitment)?;
+        // Ensure the state path is for the same state root.
+        ensure!(
+            state_path.global_state_root() == self.state_root,
+            "The prefetched state paths are stale, as the state root has advanced"
+        );
+        Ok(state_path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        store::helpers::memory::BlockMemory,
+        vm::test_helpers::{sample_genesis_block, sample_vm_with_genesis_block},
+    };
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_prefetched_query() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the VM with the genesis block.
+        let vm = sample_vm_with_genesis_block(rng);
+        let query = Query::<CurrentNetwork, BlockMemory<CurrentNetwork>>::from(vm.block_store());
+
+        // Fetch the commitments of the genesis block.
+        let commitments = sample_genesis_block(rng).into_commitments().collect::<Vec<_>>();
+
+        // Prefetch the state paths.
+        let prefetched = PrefetchedQuery::new(query.clone(), &commitments).unwrap();
+        assert_eq!(prefetched.current_state_root(), query.current_state_root().unwrap());
+
+        // Ensure the prefetched state paths match the individual lookups.
+        for commitment in &commitments {
+            assert_eq!(
+                prefetched.get_state_path_for_commitment(commitment).unwrap(),
+                query.get_state_path_for_commitment(commitment).unwrap()
+            );
+        }
+
+        // Ensure the prefetched state paths are served without querying, by swapping in an unreachable node.
+        let unreachable = Query::<CurrentNetwork, BlockMemory<CurrentNetwork>>::from("http://127.0.0.1:1");
+        let cached = PrefetchedQuery { query: unreachable, ..prefetched.clone() };
+        for commitment in &commitments {
+            assert_eq!(
+                cached.get_state_path_for_commitment(commitment).unwrap(),
+                prefetched.get_state_path_for_commitment(commitment).unwrap()
+            );
+        }
+        // Ensure a commitment that was not prefetched is queried.
+        assert!(cached.get_state_path_for_commitment(&Field::rand(rng)).is_err());
+    }
+}
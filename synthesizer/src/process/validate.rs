@@ -0,0 +1,52 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Process<N> {
+    /// Validates the given program, given its (already-validated) imports.
+    /// This checks that each import is resolved, and that the closures, functions, and finalize blocks are well-typed.
+    #[inline]
+    pub fn validate_program(program: &Program<N>, imports: &[Program<N>]) -> Result<()> {
+        let timer = timer!("Process::validate_program");
+
+        // Ensure each import of the program is resolved.
+        for import_id in program.imports().keys() {
+            ensure!(
+                imports.iter().any(|import| import.id() == import_id),
+                "Program '{}' imports '{import_id}', which is not among the validated programs",
+                program.id()
+            );
+        }
+        lap!(timer, "Resolve the imports");
+
+        // Initialize an empty process.
+        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        // Add the imports to the process, in order.
+        for import in imports {
+            if let Err(error) = process.add_program(import) {
+                bail!("Failed to load the import '{}' of program '{}': {error}", import.id(), program.id())
+            }
+        }
+        lap!(timer, "Load the imports");
+
+        // Ensure the program is well-formed and well-typed.
+        if let Err(error) = Stack::new(&process, program) {
+            bail!("Program '{}' is invalid: {error}", program.id())
+        }
+        finish!(timer);
+
+        Ok(())
+    }
+}
@@ -118,6 +118,28 @@ impl<N: Network> Process<N> {
 
         Ok(())
     }
+
+    /// Verifies the given fee covers the deployment cost of the given deployment.
+    /// Note: This does *not* verify the fee itself, see `Process::verify_fee`.
+    #[inline]
+    pub fn verify_deployment_fee(&self, deployment: &Deployment<N>, fee: &Fee<N>) -> Result<()> {
+        // Compute the deployment cost, in microcredits.
+        let deployment_cost = deployment
+            .size_in_bytes()?
+            .checked_mul(N::DEPLOYMENT_FEE_MULTIPLIER)
+            .ok_or_else(|| anyhow!("Deployment cost overflowed for program '{}'", deployment.program_id()))?;
+        // Retrieve the fee amount, in microcredits.
+        let fee_amount = *fee.amount()?;
+        // Ensure the fee covers the deployment cost.
+        if fee_amount < deployment_cost {
+            bail!(
+                "Insufficient fee to deploy '{}' - requires {deployment_cost}, found {fee_amount} (short by {} microcredits)",
+                deployment.program_id(),
+                deployment_cost - fee_amount
+            )
+        }
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -141,6 +163,10 @@ mod tests {
                 let deployment_id = deployment.to_deployment_id().unwrap();
 
                 assert!(process.verify_fee(&fee, deployment_id).is_ok());
+
+                // Ensure the fee fails to verify against a different deployment or execution ID.
+                let error = process.verify_fee(&fee, deployment_id + Field::one()).unwrap_err();
+                assert!(error.to_string().contains("Incorrect deployment or execution ID"), "{error}");
             }
             _ => panic!("Expected a deployment transaction"),
         }
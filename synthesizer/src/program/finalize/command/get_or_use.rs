@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use crate::{
+    finalize::Get,
     FinalizeStorage,
     FinalizeStore,
     Opcode,
@@ -43,6 +44,12 @@ pub struct GetOrUse<N: Network> {
 }
 
 impl<N: Network> GetOrUse<N> {
+    /// Initializes a `get.or_use` command from the given `get` command and default value.
+    #[inline]
+    pub fn with_default(get: Get<N>, default: Operand<N>) -> Self {
+        Self { mapping: *get.mapping_name(), key: get.key().clone(), default, destination: get.destination().clone() }
+    }
+
     /// Returns the opcode.
     #[inline]
     pub const fn opcode() -> Opcode {
@@ -243,4 +250,20 @@ mod tests {
         assert_eq!(get_or_use.default, Operand::Register(Register::Locator(1)), "The second operand is incorrect");
         assert_eq!(get_or_use.destination, Register::Locator(2), "The second operand is incorrect");
     }
+
+    #[test]
+    fn test_accessors() {
+        let (_, get_or_use) = GetOrUse::<CurrentNetwork>::parse("get.or_use object[r0] r1 into r2;").unwrap();
+        assert_eq!(get_or_use.mapping_name(), &Identifier::from_str("object").unwrap());
+        assert_eq!(get_or_use.key(), &Operand::Register(Register::Locator(0)));
+        assert_eq!(get_or_use.default(), &Operand::Register(Register::Locator(1)));
+        assert_eq!(get_or_use.destination(), &Register::Locator(2));
+    }
+
+    #[test]
+    fn test_with_default() {
+        let (_, get) = Get::<CurrentNetwork>::parse("get account[r0] into r1;").unwrap();
+        let get_or_use = GetOrUse::with_default(get, Operand::from_str("0u64").unwrap());
+        assert_eq!(get_or_use.to_string(), "get.or_use account[r0] 0u64 into r1;");
+    }
 }
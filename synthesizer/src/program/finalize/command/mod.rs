@@ -24,8 +24,8 @@ pub use get_or_use::*;
 mod set;
 pub use set::*;
 
-use crate::{program::Instruction, FinalizeOperation, FinalizeRegisters, FinalizeStorage, FinalizeStore, Stack};
-use console::network::prelude::*;
+use crate::{program::Instruction, FinalizeOperation, FinalizeRegisters, FinalizeStorage, FinalizeStore, Operand, Stack};
+use console::{network::prelude::*, program::Register};
 
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub enum Command<N: Network> {
@@ -41,6 +41,47 @@ pub enum Command<N: Network> {
 }
 
 impl<N: Network> Command<N> {
+    /// Returns the registers read by the command.
+    #[inline]
+    pub fn operand_registers(&self) -> Vec<Register<N>> {
+        let operands = match self {
+            Command::Instruction(instruction) => instruction.operands().to_vec(),
+            Command::Get(get) => get.operands(),
+            Command::GetOrUse(get_or_use) => get_or_use.operands(),
+            Command::Set(set) => set.operands(),
+        };
+        operands
+            .into_iter()
+            .filter_map(|operand| match operand {
+                Operand::Register(register) => Some(register),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Returns the registers written by the command.
+    #[inline]
+    pub fn destination_registers(&self) -> Vec<Register<N>> {
+        match self {
+            Command::Instruction(instruction) => instruction.destinations(),
+            Command::Get(get) => vec![get.destination().clone()],
+            Command::GetOrUse(get_or_use) => vec![get_or_use.destination().clone()],
+            Command::Set(_) => vec![],
+        }
+    }
+
+    /// Returns `true` if the command reads from or writes to storage.
+    #[inline]
+    pub const fn is_storage_command(&self) -> bool {
+        matches!(self, Command::Get(..) | Command::GetOrUse(..) | Command::Set(..))
+    }
+
+    /// Returns `true` if the command only evaluates an instruction.
+    #[inline]
+    pub const fn is_compute_command(&self) -> bool {
+        matches!(self, Command::Instruction(..))
+    }
+
     /// Finalizes the command.
     #[inline]
     pub fn finalize<P: FinalizeStorage<N>>(
@@ -173,6 +214,47 @@ mod tests {
 
     type CurrentNetwork = Testnet3;
 
+    #[test]
+    fn test_command_registers() {
+        let register = |string: &str| Register::<CurrentNetwork>::from_str(string).unwrap();
+
+        // Instruction
+        let command = Command::<CurrentNetwork>::from_str("add r0 1u64 into r1;").unwrap();
+        assert_eq!(command.operand_registers(), vec![register("r0")]);
+        assert_eq!(command.destination_registers(), vec![register("r1")]);
+
+        // Get
+        let command = Command::<CurrentNetwork>::from_str("get object[r0] into r1;").unwrap();
+        assert_eq!(command.operand_registers(), vec![register("r0")]);
+        assert_eq!(command.destination_registers(), vec![register("r1")]);
+
+        // GetOrUse
+        let command = Command::<CurrentNetwork>::from_str("get.or_use object[r0] r1 into r2;").unwrap();
+        assert_eq!(command.operand_registers(), vec![register("r0"), register("r1")]);
+        assert_eq!(command.destination_registers(), vec![register("r2")]);
+
+        // Set
+        let command = Command::<CurrentNetwork>::from_str("set r0 into object[r1];").unwrap();
+        assert_eq!(command.operand_registers().len(), 2);
+        assert!(command.operand_registers().contains(&register("r0")));
+        assert!(command.operand_registers().contains(&register("r1")));
+        assert!(command.destination_registers().is_empty());
+    }
+
+    #[test]
+    fn test_command_predicates() {
+        for (string, is_storage) in [
+            ("add r0 1u64 into r1;", false),
+            ("get object[r0] into r1;", true),
+            ("get.or_use object[r0] r1 into r2;", true),
+            ("set r0 into object[r1];", true),
+        ] {
+            let command = Command::<CurrentNetwork>::from_str(string).unwrap();
+            assert_eq!(command.is_storage_command(), is_storage, "{string}");
+            assert_eq!(command.is_compute_command(), !is_storage, "{string}");
+        }
+    }
+
     #[test]
     fn test_command_bytes() {
         // Decrement
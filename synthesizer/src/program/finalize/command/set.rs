@@ -12,10 +12,20 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{FinalizeOperation, FinalizeStorage, FinalizeStore, Opcode, Operand, RegistersLoad, Stack, StackProgram};
+use crate::{
+    FinalizeOperation,
+    FinalizeStorage,
+    FinalizeStore,
+    Opcode,
+    Operand,
+    Program,
+    RegistersLoad,
+    Stack,
+    StackProgram,
+};
 use console::{
     network::prelude::*,
-    program::{Identifier, Value},
+    program::{Identifier, PlaintextType, Value},
 };
 
 /// A set command, e.g. `set r1 into mapping[r0];`
@@ -60,6 +70,18 @@ impl<N: Network> Set<N> {
     pub const fn value(&self) -> &Operand<N> {
         &self.value
     }
+
+    /// Returns the declared key type of the mapping, from the given program.
+    #[inline]
+    pub fn key_type_hint(&self, program: &Program<N>) -> Result<PlaintextType<N>> {
+        Ok(*program.get_mapping(&self.mapping)?.key().plaintext_type())
+    }
+
+    /// Returns the declared value type of the mapping, from the given program.
+    #[inline]
+    pub fn value_type_hint(&self, program: &Program<N>) -> Result<PlaintextType<N>> {
+        Ok(*program.get_mapping(&self.mapping)?.value().plaintext_type())
+    }
 }
 
 impl<N: Network> Set<N> {
@@ -207,4 +229,31 @@ mod tests {
         assert_eq!(set.value, Operand::Register(Register::Locator(0)), "The first operand is incorrect");
         assert_eq!(set.key, Operand::Register(Register::Locator(1)), "The second operand is incorrect");
     }
+
+    #[test]
+    fn test_type_hints() {
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program token.aleo;
+
+mapping account:
+    key owner as address.public;
+    value amount as u64.public;
+
+function noop:
+    input r0 as u64.private;
+    output r0 as u64.private;",
+        )
+        .unwrap();
+
+        // Ensure the declared types of a known mapping are returned.
+        let (_, set) = Set::<CurrentNetwork>::parse("set r0 into account[r1];").unwrap();
+        assert_eq!(set.key_type_hint(&program).unwrap(), PlaintextType::from_str("address").unwrap());
+        assert_eq!(set.value_type_hint(&program).unwrap(), PlaintextType::from_str("u64").unwrap());
+
+        // Ensure an unknown mapping fails.
+        let (_, set) = Set::<CurrentNetwork>::parse("set r0 into unknown[r1];").unwrap();
+        assert!(set.key_type_hint(&program).is_err());
+        assert!(set.value_type_hint(&program).is_err());
+    }
 }
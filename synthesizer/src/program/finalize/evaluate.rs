@@ -0,0 +1,57 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::{
+    FinalizeOperation,
+    FinalizeRegisters,
+    FinalizeStorage,
+    FinalizeStore,
+    Stack,
+};
+
+impl<N: Network> Finalize<N> {
+    /// Evaluates the commands of the finalize scope on the given registers and store,
+    /// returning the finalize operations in order.
+    ///
+    /// Note: The inputs are expected to already be stored in the registers.
+    pub(crate) fn evaluate_commands<P: FinalizeStorage<N>>(
+        &self,
+        stack: &Stack<N>,
+        store: &FinalizeStore<N, P>,
+        registers: &mut FinalizeRegisters<N>,
+    ) -> Result<Vec<FinalizeOperation<N>>> {
+        // Initialize a list for finalize operations.
+        let mut finalize_operations = Vec::new();
+
+        // Evaluate the commands.
+        for command in &self.commands {
+            let result =
+                std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| command.finalize(stack, store, registers)));
+            match result {
+                // If the evaluation succeeds with an operation, add it to the list.
+                Ok(Ok(Some(finalize_operation))) => finalize_operations.push(finalize_operation),
+                // If the evaluation succeeds with no operation, continue.
+                Ok(Ok(None)) => (),
+                // If the evaluation fails, bail and return the error.
+                Ok(Err(error)) => bail!("'finalize' failed to evaluate command ({command}): {error}"),
+                // If the evaluation fails, bail and return the error.
+                Err(_) => bail!("'finalize' failed to evaluate command ({command})"),
+            }
+        }
+
+        // Return the finalize operations.
+        Ok(finalize_operations)
+    }
+}
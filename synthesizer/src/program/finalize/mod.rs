@@ -18,8 +18,13 @@ pub use command::*;
 mod input;
 use input::*;
 
+mod register_usage;
+pub use register_usage::*;
+
 mod bytes;
+mod evaluate;
 mod parse;
+mod simulate;
 
 use crate::Instruction;
 use console::{
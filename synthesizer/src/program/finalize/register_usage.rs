@@ -0,0 +1,117 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use std::collections::{HashMap, HashSet};
+
+/// The commands that write to and read from a register, given as command indices.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RegisterUsage {
+    /// The indices of the commands that write to the register.
+    pub writes: Vec<usize>,
+    /// The indices of the commands that read from the register.
+    pub reads: Vec<usize>,
+}
+
+impl<N: Network> Finalize<N> {
+    /// Returns the usage of each register in finalize, keyed by the register locator.
+    pub fn find_register_usage(&self) -> HashMap<u64, RegisterUsage> {
+        let mut usage = HashMap::<u64, RegisterUsage>::new();
+
+        for (index, command) in self.commands.iter().enumerate() {
+            // Record the registers read by the command.
+            for register in command.operand_registers() {
+                usage.entry(register.locator()).or_default().reads.push(index);
+            }
+            // Record the registers written by the command.
+            for register in command.destination_registers() {
+                usage.entry(register.locator()).or_default().writes.push(index);
+            }
+        }
+
+        usage
+    }
+
+    /// Returns the locators of the registers declared in finalize, as inputs or as command destinations.
+    pub fn registers_declared(&self) -> HashSet<u64> {
+        let usage = self.find_register_usage();
+        self.inputs
+            .iter()
+            .map(|input| input.register().locator())
+            .chain(usage.into_iter().filter(|(_, usage)| !usage.writes.is_empty()).map(|(locator, _)| locator))
+            .collect()
+    }
+
+    /// Returns the locators of the registers used as command operands in finalize.
+    pub fn registers_used(&self) -> HashSet<u64> {
+        self.find_register_usage()
+            .into_iter()
+            .filter(|(_, usage)| !usage.reads.is_empty())
+            .map(|(locator, _)| locator)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_find_register_usage() {
+        let finalize = Finalize::<CurrentNetwork>::from_str(
+            r"
+finalize main:
+    input r0 as field.public;
+    add r0 r0 into r1;
+    add r1 r0 into r2;
+    set r2 into values[r1];",
+        )
+        .unwrap();
+
+        let usage = finalize.find_register_usage();
+        assert_eq!(usage.len(), 3);
+        // The input register is only read.
+        assert_eq!(usage[&0], RegisterUsage { writes: vec![], reads: vec![0, 0, 1] });
+        // `r1` is written by command 0, and read by commands 1 and 2.
+        assert_eq!(usage[&1], RegisterUsage { writes: vec![0], reads: vec![1, 2] });
+        // `r2` is written by command 1, and read by command 2.
+        assert_eq!(usage[&2], RegisterUsage { writes: vec![1], reads: vec![2] });
+    }
+
+    #[test]
+    fn test_registers_declared_and_used() {
+        let finalize = Finalize::<CurrentNetwork>::from_str(
+            r"
+finalize main:
+    input r0 as field.public;
+    add r0 r0 into r1;
+    add r0 r0 into r2;
+    set r2 into values[r0];",
+        )
+        .unwrap();
+
+        // `r0` is declared as an input, and `r1` and `r2` are declared as destinations.
+        assert_eq!(finalize.registers_declared(), HashSet::from([0, 1, 2]));
+        // `r0` and `r2` are read.
+        assert_eq!(finalize.registers_used(), HashSet::from([0, 2]));
+        // `r1` is a dead write.
+        assert_eq!(finalize.registers_declared().difference(&finalize.registers_used()).collect::<Vec<_>>(), [&1]);
+        // There are no undeclared reads.
+        assert!(finalize.registers_used().is_subset(&finalize.registers_declared()));
+    }
+}
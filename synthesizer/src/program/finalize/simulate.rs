@@ -0,0 +1,63 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::{
+    FinalizeGlobalState,
+    FinalizeOperation,
+    FinalizeRegisters,
+    FinalizeStorage,
+    FinalizeStore,
+    RegistersStore,
+    Stack,
+    StackProgram,
+};
+use console::program::{Plaintext, Value};
+
+impl<N: Network> Finalize<N> {
+    /// Simulates the finalize scope on the given inputs, returning the finalize operations and the final registers.
+    /// The commands are evaluated against an in-memory copy of the program's confirmed mappings,
+    /// so the given store is only read, and is left unchanged.
+    pub fn simulate<P: FinalizeStorage<N>>(
+        &self,
+        stack: &Stack<N>,
+        store: &FinalizeStore<N, P>,
+        state: FinalizeGlobalState,
+        inputs: &[Plaintext<N>],
+    ) -> Result<(Vec<FinalizeOperation<N>>, FinalizeRegisters<N>)> {
+        // Ensure the number of inputs matches.
+        ensure!(
+            self.inputs.len() == inputs.len(),
+            "'finalize' for '{}' expects {} inputs, but {} were found",
+            self.name,
+            self.inputs.len(),
+            inputs.len()
+        );
+
+        // Initialize the registers.
+        let mut registers = FinalizeRegisters::<N>::new(state, stack.get_finalize_types(&self.name)?.clone());
+        // Store the inputs.
+        for (input, value) in self.inputs.iter().zip_eq(inputs) {
+            registers.store(stack, input.register(), Value::Plaintext(value.clone()))?;
+        }
+
+        // Copy the mappings of the program, so that the simulation does not touch the given store.
+        let copy = store.copy_program_to_memory(stack.program_id())?;
+        // Evaluate the commands.
+        let finalize_operations = self.evaluate_commands(stack, &copy, &mut registers)?;
+
+        // Return the finalize operations and the registers.
+        Ok((finalize_operations, registers))
+    }
+}
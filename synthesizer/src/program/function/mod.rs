@@ -24,6 +24,7 @@ mod parse;
 use crate::{
     program::finalize::{Finalize, FinalizeCommand},
     Instruction,
+    Operand,
 };
 use console::{
     network::prelude::*,
@@ -160,6 +161,79 @@ impl<N: Network> Function<N> {
         Ok(())
     }
 
+    /// Removes the trailing instructions whose destination registers are never read, and returns the number removed.
+    /// A register is read if it is used as an operand of an instruction, an output, or the finalize command.
+    ///
+    /// Note: Only the instruction that writes the highest register is considered for removal (repeatedly),
+    /// so that the remaining registers stay in order. Instructions that may halt (e.g. checked arithmetic
+    /// and casts) and `call` instructions are never removed, as removing them would change the program.
+    #[inline]
+    pub fn remove_dead_registers(&mut self) -> usize {
+        let num_instructions = self.instructions.len();
+
+        // Iterate until no more instructions are removed, as removing an instruction may render others dead.
+        // Note: The last instruction that writes to a register is the one that writes the highest register.
+        while let Some(index) = self.instructions.iter().rposition(|i| !i.destinations().is_empty()) {
+            let instruction = &self.instructions[index];
+
+            // Ensure the instruction can be removed without changing the behavior of the function.
+            if !Self::is_removable(instruction) {
+                break;
+            }
+
+            // Collect the locators of all registers that are read.
+            let mut operands = self
+                .instructions
+                .iter()
+                .flat_map(|instruction| instruction.operands().iter())
+                .chain(self.outputs.iter().map(|output| output.operand()))
+                .chain(self.finalize.iter().flat_map(|(command, _)| command.operands().iter()));
+            let destinations = instruction.destinations();
+            let is_live = operands.any(|operand| match operand {
+                Operand::Register(register) => {
+                    destinations.iter().any(|destination| destination.locator() == register.locator())
+                }
+                _ => false,
+            });
+            if is_live {
+                break;
+            }
+
+            // Remove the dead instruction.
+            self.instructions.remove(index);
+        }
+
+        num_instructions - self.instructions.len()
+    }
+
+    /// Returns `true` if the given instruction never halts and has no effects beyond its destination registers.
+    #[inline]
+    fn is_removable(instruction: &Instruction<N>) -> bool {
+        matches!(
+            instruction,
+            Instruction::AbsWrapped(..)
+                | Instruction::AddWrapped(..)
+                | Instruction::And(..)
+                | Instruction::GreaterThan(..)
+                | Instruction::GreaterThanOrEqual(..)
+                | Instruction::IsEq(..)
+                | Instruction::IsNeq(..)
+                | Instruction::LessThan(..)
+                | Instruction::LessThanOrEqual(..)
+                | Instruction::MulWrapped(..)
+                | Instruction::Nand(..)
+                | Instruction::Nor(..)
+                | Instruction::Not(..)
+                | Instruction::Or(..)
+                | Instruction::PowWrapped(..)
+                | Instruction::ShlWrapped(..)
+                | Instruction::ShrWrapped(..)
+                | Instruction::SubWrapped(..)
+                | Instruction::Ternary(..)
+                | Instruction::Xor(..)
+        )
+    }
+
     /// Adds the output statement to the function.
     ///
     /// # Errors
@@ -216,3 +290,83 @@ impl<N: Network> TypeName for Function<N> {
         "function"
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_remove_dead_registers() {
+        let mut function = Function::<CurrentNetwork>::from_str(
+            r"
+function compute:
+    input r0 as u32.private;
+    input r1 as u32.public;
+    add r0 r1 into r2;
+    add.w r0 r0 into r3;
+    output r2 as u32.public;",
+        )
+        .unwrap();
+        assert_eq!(function.instructions().len(), 2);
+
+        // Ensure the unused instruction is removed.
+        assert_eq!(function.remove_dead_registers(), 1);
+        assert_eq!(function.instructions().len(), 1);
+        assert_eq!(function.instructions()[0], Instruction::from_str("add r0 r1 into r2;").unwrap());
+
+        // Ensure no further instructions are removed.
+        assert_eq!(function.remove_dead_registers(), 0);
+        assert_eq!(function.instructions().len(), 1);
+    }
+
+    #[test]
+    fn test_remove_dead_registers_transitively() {
+        let mut function = Function::<CurrentNetwork>::from_str(
+            r"
+function compute:
+    input r0 as u32.private;
+    add.w r0 r0 into r1;
+    add.w r1 r1 into r2;
+    assert.eq r0 r0;
+    output r0 as u32.public;",
+        )
+        .unwrap();
+
+        // Ensure both the dead instruction and the instruction it depends on are removed.
+        assert_eq!(function.remove_dead_registers(), 2);
+        assert_eq!(function.instructions().len(), 1);
+        assert_eq!(function.instructions()[0], Instruction::from_str("assert.eq r0 r0;").unwrap());
+    }
+
+    #[test]
+    fn test_remove_dead_registers_keeps_middle_and_halting() {
+        // Ensure a dead write that is followed by a live write is kept, so the registers stay in order.
+        let mut function = Function::<CurrentNetwork>::from_str(
+            r"
+function compute:
+    input r0 as u32.private;
+    add.w r0 r0 into r1;
+    add.w r0 r0 into r2;
+    output r2 as u32.public;",
+        )
+        .unwrap();
+        assert_eq!(function.remove_dead_registers(), 0);
+        assert_eq!(function.instructions().len(), 2);
+
+        // Ensure a dead write that may halt is kept.
+        let mut function = Function::<CurrentNetwork>::from_str(
+            r"
+function compute:
+    input r0 as u32.private;
+    input r1 as u32.private;
+    div r0 r1 into r2;
+    output r0 as u32.public;",
+        )
+        .unwrap();
+        assert_eq!(function.remove_dead_registers(), 0);
+        assert_eq!(function.instructions()[0], Instruction::from_str("div r0 r1 into r2;").unwrap());
+    }
+}
@@ -347,6 +347,46 @@ impl<N: Network> Instruction<N> {
         instruction!(self, |InstructionMember| InstructionMember::<N>::opcode())
     }
 
+    /// Returns the mnemonic of the instruction, i.e. `add` or `hash.bhp256`.
+    #[inline]
+    pub fn opcode_name(&self) -> &'static str {
+        *self.opcode()
+    }
+
+    /// Initializes a new instruction from the given mnemonic, operands, and destination registers.
+    ///
+    /// Note: The following instructions declare additional information beyond their operands and
+    /// destinations (i.e. a callee, a cast type, or an output type), and are rejected by this method:
+    ///   - `call` and `cast`
+    ///   - `commit.bhp256`, `commit.bhp512`, `commit.bhp768`, `commit.bhp1024`, `commit.ped64`, `commit.ped128`
+    ///   - `hash.bhp256`, `hash.bhp512`, `hash.bhp768`, `hash.bhp1024`, `hash.ped64`, `hash.ped128`,
+    ///     `hash.psd2`, `hash.psd4`, `hash.psd8`, `hash_many.psd2`, `hash_many.psd4`, `hash_many.psd8`
+    pub fn from_opcode_name(name: &str, operands: Vec<Operand<N>>, destinations: Vec<Register<N>>) -> Result<Self> {
+        // Retrieve the opcode for the given mnemonic.
+        let opcode = match Self::OPCODES.iter().find(|opcode| ***opcode == name) {
+            Some(opcode) => opcode,
+            None => bail!("Failed to find an instruction with the opcode '{name}'"),
+        };
+        // Ensure the instruction does not declare additional information.
+        if let Opcode::Call | Opcode::Cast | Opcode::Commit(..) | Opcode::Hash(..) = opcode {
+            bail!("Cannot construct '{name}' from its operands and destinations alone")
+        }
+
+        // Construct the instruction string.
+        let operands = operands.iter().map(|operand| operand.to_string()).collect::<Vec<_>>().join(" ");
+        let destinations = destinations.iter().map(|register| register.to_string()).collect::<Vec<_>>().join(" ");
+        let string = match destinations.is_empty() {
+            true => format!("{name} {operands};"),
+            false => format!("{name} {operands} into {destinations};"),
+        };
+
+        // Parse the instruction, which ensures the operands and destinations are well-formed.
+        let instruction = Self::from_str(&string)?;
+        // Ensure the parsed instruction matches the given mnemonic.
+        ensure!(instruction.opcode_name() == name, "Expected '{name}', found '{}'", instruction.opcode_name());
+        Ok(instruction)
+    }
+
     /// Returns the operands of the instruction.
     #[inline]
     pub fn operands(&self) -> &[Operand<N>] {
@@ -419,6 +459,8 @@ mod tests {
     use super::*;
     use console::network::Testnet3;
 
+    use std::collections::HashSet;
+
     type CurrentNetwork = Testnet3;
 
     #[test]
@@ -430,4 +472,170 @@ mod tests {
             "Update me if the number of instructions changes."
         );
     }
+
+    /// Returns a sample of each instruction variant, along with its expected mnemonic.
+    /// Note: The `hash_many` variants are excluded, as they are not yet implemented.
+    fn sample_instructions() -> Vec<(&'static str, &'static str)> {
+        vec![
+            ("abs", "abs r0 into r1;"),
+            ("abs.w", "abs.w r0 into r1;"),
+            ("add", "add r0 r1 into r2;"),
+            ("add.w", "add.w r0 r1 into r2;"),
+            ("and", "and r0 r1 into r2;"),
+            ("assert.eq", "assert.eq r0 r1;"),
+            ("assert.neq", "assert.neq r0 r1;"),
+            ("call", "call foo r0 r1 into r2;"),
+            ("cast", "cast r0 r1 into r2 as foo;"),
+            ("commit.bhp256", "commit.bhp256 r0 r1 into r2 as field;"),
+            ("commit.bhp512", "commit.bhp512 r0 r1 into r2 as field;"),
+            ("commit.bhp768", "commit.bhp768 r0 r1 into r2 as field;"),
+            ("commit.bhp1024", "commit.bhp1024 r0 r1 into r2 as field;"),
+            ("commit.ped64", "commit.ped64 r0 r1 into r2 as field;"),
+            ("commit.ped128", "commit.ped128 r0 r1 into r2 as field;"),
+            ("div", "div r0 r1 into r2;"),
+            ("div.w", "div.w r0 r1 into r2;"),
+            ("double", "double r0 into r1;"),
+            ("gt", "gt r0 r1 into r2;"),
+            ("gte", "gte r0 r1 into r2;"),
+            ("hash.bhp256", "hash.bhp256 r0 into r1 as field;"),
+            ("hash.bhp512", "hash.bhp512 r0 into r1 as field;"),
+            ("hash.bhp768", "hash.bhp768 r0 into r1 as field;"),
+            ("hash.bhp1024", "hash.bhp1024 r0 into r1 as field;"),
+            ("hash.ped64", "hash.ped64 r0 into r1 as field;"),
+            ("hash.ped128", "hash.ped128 r0 into r1 as field;"),
+            ("hash.psd2", "hash.psd2 r0 into r1 as field;"),
+            ("hash.psd4", "hash.psd4 r0 into r1 as field;"),
+            ("hash.psd8", "hash.psd8 r0 into r1 as field;"),
+            ("inv", "inv r0 into r1;"),
+            ("is.eq", "is.eq r0 r1 into r2;"),
+            ("is.neq", "is.neq r0 r1 into r2;"),
+            ("lt", "lt r0 r1 into r2;"),
+            ("lte", "lte r0 r1 into r2;"),
+            ("mod", "mod r0 r1 into r2;"),
+            ("mul", "mul r0 r1 into r2;"),
+            ("mul.w", "mul.w r0 r1 into r2;"),
+            ("nand", "nand r0 r1 into r2;"),
+            ("neg", "neg r0 into r1;"),
+            ("nor", "nor r0 r1 into r2;"),
+            ("not", "not r0 into r1;"),
+            ("or", "or r0 r1 into r2;"),
+            ("pow", "pow r0 r1 into r2;"),
+            ("pow.w", "pow.w r0 r1 into r2;"),
+            ("rem", "rem r0 r1 into r2;"),
+            ("rem.w", "rem.w r0 r1 into r2;"),
+            ("shl", "shl r0 r1 into r2;"),
+            ("shl.w", "shl.w r0 r1 into r2;"),
+            ("shr", "shr r0 r1 into r2;"),
+            ("shr.w", "shr.w r0 r1 into r2;"),
+            ("square", "square r0 into r1;"),
+            ("sqrt", "sqrt r0 into r1;"),
+            ("sub", "sub r0 r1 into r2;"),
+            ("sub.w", "sub.w r0 r1 into r2;"),
+            ("ternary", "ternary r0 r1 r2 into r3;"),
+            ("xor", "xor r0 r1 into r2;"),
+        ]
+    }
+
+    #[test]
+    fn test_opcode_name() -> Result<()> {
+        let samples = sample_instructions();
+        // Ensure every instruction variant is sampled, except for the three `hash_many` variants.
+        assert_eq!(Instruction::<CurrentNetwork>::OPCODES.len(), samples.len() + 3);
+
+        for (expected, instruction) in &samples {
+            let instruction = Instruction::<CurrentNetwork>::from_str(instruction)?;
+            assert_eq!(*expected, instruction.opcode_name());
+            assert_eq!(expected, &instruction.opcode().to_string());
+        }
+
+        // Ensure no two instruction variants share the same mnemonic.
+        let names = Instruction::<CurrentNetwork>::OPCODES.iter().map(|opcode| **opcode).collect::<HashSet<_>>();
+        assert_eq!(Instruction::<CurrentNetwork>::OPCODES.len(), names.len());
+        for name in ["hash_many.psd2", "hash_many.psd4", "hash_many.psd8"] {
+            assert!(names.contains(name));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_opcode_name() -> Result<()> {
+        for (name, instruction) in sample_instructions() {
+            let expected = Instruction::<CurrentNetwork>::from_str(instruction)?;
+            let operands = expected.operands().to_vec();
+            let destinations = expected.destinations();
+
+            let candidate = Instruction::from_opcode_name(expected.opcode_name(), operands, destinations);
+            match expected.opcode() {
+                // Ensure instructions that declare additional information are rejected.
+                Opcode::Call | Opcode::Cast | Opcode::Commit(..) | Opcode::Hash(..) => assert!(candidate.is_err()),
+                // Ensure all other instructions round-trip.
+                _ => assert_eq!(expected, candidate?, "Failed to round-trip '{name}'"),
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_opcode_name_unsupported() {
+        const UNSUPPORTED: [&str; 20] = [
+            "call",
+            "cast",
+            "commit.bhp256",
+            "commit.bhp512",
+            "commit.bhp768",
+            "commit.bhp1024",
+            "commit.ped64",
+            "commit.ped128",
+            "hash.bhp256",
+            "hash.bhp512",
+            "hash.bhp768",
+            "hash.bhp1024",
+            "hash.ped64",
+            "hash.ped128",
+            "hash.psd2",
+            "hash.psd4",
+            "hash.psd8",
+            "hash_many.psd2",
+            "hash_many.psd4",
+            "hash_many.psd8",
+        ];
+
+        let operands = vec![Operand::Register(Register::<CurrentNetwork>::Locator(0))];
+        let destinations = vec![Register::<CurrentNetwork>::Locator(1)];
+
+        // Ensure the unsupported opcodes are exactly the ones that declare additional information.
+        let candidates = Instruction::<CurrentNetwork>::OPCODES
+            .iter()
+            .filter(|opcode| matches!(opcode, Opcode::Call | Opcode::Cast | Opcode::Commit(..) | Opcode::Hash(..)))
+            .map(|opcode| **opcode)
+            .collect::<Vec<_>>();
+        assert_eq!(candidates, UNSUPPORTED);
+
+        // Ensure each unsupported opcode is rejected.
+        for name in UNSUPPORTED {
+            let candidate = Instruction::from_opcode_name(name, operands.clone(), destinations.clone());
+            assert!(candidate.is_err(), "'{name}' should be rejected");
+        }
+    }
+
+    #[test]
+    fn test_from_opcode_name_fails() -> Result<()> {
+        let r0 = Register::<CurrentNetwork>::Locator(0);
+        let r1 = Register::<CurrentNetwork>::Locator(1);
+        let r2 = Register::<CurrentNetwork>::Locator(2);
+        let operands = vec![Operand::Register(r0), Operand::Register(r1)];
+
+        // Ensure an unknown opcode fails.
+        assert!(Instruction::from_opcode_name("add.x", operands.clone(), vec![r2.clone()]).is_err());
+        // Ensure a finalize command is not an instruction.
+        assert!(Instruction::from_opcode_name("increment", operands.clone(), vec![]).is_err());
+        // Ensure an incorrect number of operands fails.
+        assert!(Instruction::from_opcode_name("add", operands[..1].to_vec(), vec![r2.clone()]).is_err());
+        assert!(Instruction::from_opcode_name("neg", operands.clone(), vec![r2.clone()]).is_err());
+        // Ensure a missing destination fails.
+        assert!(Instruction::from_opcode_name("add", operands.clone(), vec![]).is_err());
+        // Ensure an unexpected destination fails.
+        assert!(Instruction::from_opcode_name("assert.eq", operands, vec![r2]).is_err());
+        Ok(())
+    }
 }
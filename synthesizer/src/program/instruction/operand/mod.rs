@@ -39,6 +39,38 @@ pub enum Operand<N: Network> {
     BlockHeight,
 }
 
+impl<N: Network> Operand<N> {
+    /// Returns `true` if the operand is a literal.
+    #[inline]
+    pub const fn is_literal(&self) -> bool {
+        matches!(self, Self::Literal(..))
+    }
+
+    /// Returns `true` if the operand is a register.
+    #[inline]
+    pub const fn is_register(&self) -> bool {
+        matches!(self, Self::Register(..))
+    }
+
+    /// Returns the literal, if the operand is a literal.
+    #[inline]
+    pub const fn to_literal(&self) -> Option<&Literal<N>> {
+        match self {
+            Self::Literal(literal) => Some(literal),
+            _ => None,
+        }
+    }
+
+    /// Returns the register, if the operand is a register.
+    #[inline]
+    pub const fn to_register(&self) -> Option<&Register<N>> {
+        match self {
+            Self::Register(register) => Some(register),
+            _ => None,
+        }
+    }
+}
+
 impl<N: Network> From<Literal<N>> for Operand<N> {
     /// Initializes a new operand from a literal.
     #[inline]
@@ -107,4 +139,37 @@ mod tests {
         assert_eq!(expected, operand);
         Ok(())
     }
+
+    #[test]
+    fn test_operand_to_literal_and_register() -> Result<()> {
+        let literal = Literal::from_str("1field")?;
+        let register = Register::from_str("r0.owner")?;
+
+        // Operand::Literal
+        let operand = Operand::<CurrentNetwork>::Literal(literal.clone());
+        assert!(operand.is_literal());
+        assert!(!operand.is_register());
+        assert_eq!(Some(&literal), operand.to_literal());
+        assert_eq!(None, operand.to_register());
+
+        // Operand::Register
+        let operand = Operand::<CurrentNetwork>::Register(register.clone());
+        assert!(!operand.is_literal());
+        assert!(operand.is_register());
+        assert_eq!(None, operand.to_literal());
+        assert_eq!(Some(&register), operand.to_register());
+
+        // Operand::ProgramID, Operand::Caller, and Operand::BlockHeight
+        for operand in [
+            Operand::<CurrentNetwork>::ProgramID(ProgramID::from_str("credits.aleo")?),
+            Operand::<CurrentNetwork>::Caller,
+            Operand::<CurrentNetwork>::BlockHeight,
+        ] {
+            assert!(!operand.is_literal());
+            assert!(!operand.is_register());
+            assert_eq!(None, operand.to_literal());
+            assert_eq!(None, operand.to_register());
+        }
+        Ok(())
+    }
 }
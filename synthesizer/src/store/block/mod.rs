@@ -644,6 +644,27 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         // Return the block.
         Ok(Some(Block::from(previous_hash, header, transactions, coinbase, signature)?))
     }
+
+    /// Returns the blocks in the given range of block heights (`start` inclusive, `end` exclusive), in height order.
+    fn get_block_range_parallel(&self, start: u32, end: u32) -> Result<Vec<Block<N>>> {
+        // Ensure the range is well-formed.
+        ensure!(start <= end, "Invalid block range: the start height {start} exceeds the end height {end}");
+        // Retrieve the blocks concurrently.
+        let blocks = cfg_into_iter!(start..end)
+            .map(|height| match self.get_block_hash(height)? {
+                Some(block_hash) => self.get_block(&block_hash),
+                None => Ok(None),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        // Ensure every block in the range was found.
+        let missing = (start..end)
+            .zip(&blocks)
+            .filter_map(|(height, block)| block.is_none().then_some(height.to_string()))
+            .collect::<Vec<_>>();
+        ensure!(missing.is_empty(), "Missing blocks in storage for heights {}", missing.join(", "));
+        // Return the blocks.
+        Ok(blocks.into_iter().flatten().collect())
+    }
 }
 
 /// The block store.
@@ -878,6 +899,11 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         self.storage.get_block(block_hash)
     }
 
+    /// Returns the blocks in the given range of block heights (`start` inclusive, `end` exclusive), in height order.
+    pub fn get_block_range_parallel(&self, start: u32, end: u32) -> Result<Vec<Block<N>>> {
+        self.storage.get_block_range_parallel(start, end)
+    }
+
     /// Returns the confirmed transaction for the given `transaction ID`.
     pub fn get_confirmed_transaction(
         &self,
@@ -940,6 +966,9 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
 mod tests {
     use super::*;
     use crate::store::helpers::memory::BlockMemory;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
 
     #[test]
     fn test_insert_get_remove() {
@@ -1011,4 +1040,30 @@ mod tests {
             assert_eq!(None, candidate);
         }
     }
+
+    #[test]
+    fn test_get_block_range_parallel() {
+        // Load the genesis block.
+        let block = Block::<CurrentNetwork>::read_le(CurrentNetwork::genesis_bytes()).unwrap();
+
+        // Initialize a new block store.
+        let block_store = BlockStore::<_, BlockMemory<_>>::open(None).unwrap();
+
+        // Ensure the block range is missing.
+        assert!(block_store.get_block_range_parallel(0, 1).is_err());
+
+        // Insert the block.
+        block_store.insert(&block).unwrap();
+
+        // Ensure an empty range returns no blocks.
+        assert!(block_store.get_block_range_parallel(0, 0).unwrap().is_empty());
+        // Ensure the block range is returned in height order.
+        assert_eq!(vec![block], block_store.get_block_range_parallel(0, 1).unwrap());
+
+        // Ensure requesting beyond the tip reports the missing heights.
+        let error = block_store.get_block_range_parallel(0, 3).unwrap_err();
+        assert!(error.to_string().contains("heights 1, 2"), "{error}");
+        // Ensure an inverted range fails.
+        assert!(block_store.get_block_range_parallel(1, 0).is_err());
+    }
 }
@@ -17,11 +17,11 @@ use crate::{
     block::FinalizeOperation,
     cow_to_cloned,
     cow_to_copied,
-    store::helpers::{Map, MapRead},
+    store::helpers::{memory::FinalizeMemory, Map, MapRead},
 };
 use console::{
     network::prelude::*,
-    program::{Identifier, Plaintext, ProgramID, Value},
+    program::{Identifier, Plaintext, ProgramID, Value, FINALIZE_OPERATIONS_DEPTH},
     types::Field,
 };
 
@@ -683,6 +683,101 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
     }
 }
 
+impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
+    /// Returns the operations-replay root of the confirmed finalize state.
+    ///
+    /// The leaves are the finalize operations that reconstruct the state from an empty store,
+    /// namely an `InitializeMapping` for each mapping followed by an `InsertKeyValue` for each of its entries.
+    /// Programs are ordered by program ID, and mappings and entries are ordered by insertion.
+    ///
+    /// Note: This is *not* a commitment to the state that may be compared against any block header.
+    /// The finalize root in a block header commits only to the finalize operations of that block,
+    /// so this root only matches it for a block whose operations initialize the entire state from an empty store.
+    pub fn operations_replay_root(&self) -> Result<Field<N>> {
+        // Retrieve the program IDs, in sorted order.
+        let mut programs = self
+            .storage
+            .program_id_map()
+            .iter_confirmed()
+            .map(|(program_id, mapping_names)| (cow_to_copied!(program_id), cow_to_cloned!(mapping_names)))
+            .collect::<Vec<_>>();
+        programs.sort_unstable_by_key(|(program_id, _)| *program_id);
+
+        // Prepare the leaves.
+        let mut leaves = Vec::new();
+        for (program_id, mapping_names) in programs {
+            for mapping_name in mapping_names {
+                // Retrieve the mapping ID.
+                let mapping_id = match self.storage.mapping_id_map().get_confirmed(&(program_id, mapping_name))? {
+                    Some(mapping_id) => cow_to_copied!(mapping_id),
+                    None => bail!("Missing mapping ID for mapping '{mapping_name}' in program '{program_id}'"),
+                };
+                // Retrieve the key-value IDs.
+                let key_value_ids = match self.storage.key_value_id_map().get_confirmed(&mapping_id)? {
+                    Some(key_value_ids) => cow_to_cloned!(key_value_ids),
+                    None => bail!("Missing key-value IDs for mapping '{mapping_name}' in program '{program_id}'"),
+                };
+                // Append the leaves for the mapping and its entries.
+                leaves.push(FinalizeOperation::InitializeMapping(mapping_id).to_bits_le());
+                for (key_id, value_id) in key_value_ids {
+                    leaves.push(FinalizeOperation::InsertKeyValue(mapping_id, key_id, value_id).to_bits_le());
+                }
+            }
+        }
+
+        // Compute the finalize tree.
+        let tree = N::merkle_tree_bhp::<FINALIZE_OPERATIONS_DEPTH>(&leaves)?;
+        // Return the finalize root.
+        Ok(*tree.root())
+    }
+}
+
+impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
+    /// Returns an in-memory copy of the confirmed mappings of the given `program ID`.
+    ///
+    /// The mappings and their entries are copied in insertion order,
+    /// so that finalizing against the copy produces the same finalize operations as against this store.
+    pub fn copy_program_to_memory(&self, program_id: &ProgramID<N>) -> Result<FinalizeStore<N, FinalizeMemory<N>>> {
+        // Initialize the in-memory finalize store.
+        let copy = FinalizeStore::<N, FinalizeMemory<N>>::open(self.dev())?;
+
+        // Retrieve the mapping names of the program.
+        let mapping_names = match self.storage.program_id_map().get_confirmed(program_id)? {
+            Some(mapping_names) => cow_to_cloned!(mapping_names),
+            None => return Ok(copy),
+        };
+
+        for mapping_name in mapping_names {
+            // Initialize the mapping.
+            copy.initialize_mapping(program_id, &mapping_name)?;
+
+            // Retrieve the mapping ID.
+            let mapping_id = match self.storage.mapping_id_map().get_confirmed(&(*program_id, mapping_name))? {
+                Some(mapping_id) => cow_to_copied!(mapping_id),
+                None => bail!("Missing mapping ID for mapping '{mapping_name}' in program '{program_id}'"),
+            };
+            // Retrieve the key-value IDs.
+            let key_value_ids = match self.storage.key_value_id_map().get_confirmed(&mapping_id)? {
+                Some(key_value_ids) => cow_to_cloned!(key_value_ids),
+                None => bail!("Missing key-value IDs for mapping '{mapping_name}' in program '{program_id}'"),
+            };
+            // Copy the entries of the mapping.
+            for key_id in key_value_ids.keys() {
+                let key = match self.storage.key_map().get_confirmed(key_id)? {
+                    Some(key) => cow_to_cloned!(key),
+                    None => bail!("Missing key for key ID '{key_id}' in mapping '{mapping_name}'"),
+                };
+                let value = match self.storage.value_map().get_confirmed(key_id)? {
+                    Some(value) => cow_to_cloned!(value),
+                    None => bail!("Missing value for key ID '{key_id}' in mapping '{mapping_name}'"),
+                };
+                copy.insert_key_value(program_id, &mapping_name, key, value)?;
+            }
+        }
+        Ok(copy)
+    }
+}
+
 impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
     /// Returns the mapping names for the given `program ID`.
     pub fn get_mapping_names_speculative(&self, program_id: &ProgramID<N>) -> Result<Option<IndexSet<Identifier<N>>>> {
@@ -703,7 +798,7 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::store::helpers::memory::FinalizeMemory;
+    use crate::block::Block;
     use console::network::Testnet3;
 
     type CurrentNetwork = Testnet3;
@@ -899,6 +994,39 @@ mod tests {
         assert!(finalize_store.get_value_speculative(&program_id, &mapping_name, &key).unwrap().is_none());
     }
 
+    #[test]
+    fn test_operations_replay_root() {
+        // Initialize a new finalize store.
+        let finalize_store = FinalizeStore::<CurrentNetwork, FinalizeMemory<CurrentNetwork>>::open(None).unwrap();
+        // Ensure the root of the empty store matches the root of no finalize operations.
+        assert_eq!(
+            finalize_store.operations_replay_root().unwrap(),
+            Block::compute_expected_finalize_root(&[]).unwrap()
+        );
+
+        // Prepare the program ID and mapping name.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+
+        // Populate the store, and collect the finalize operations.
+        let mut operations = vec![finalize_store.initialize_mapping(&program_id, &mapping_name).unwrap()];
+        for i in 0..4u64 {
+            let key = Plaintext::from_str(&format!("{i}field")).unwrap();
+            let value = Value::from_str(&format!("{}u64", i * 100)).unwrap();
+            operations.push(finalize_store.insert_key_value(&program_id, &mapping_name, key, value).unwrap());
+        }
+
+        // Ensure the root matches the finalize root of a block with the same finalize operations.
+        let expected = Block::compute_expected_finalize_root(&operations).unwrap();
+        assert_eq!(finalize_store.operations_replay_root().unwrap(), expected);
+
+        // Ensure the root changes when a value is updated.
+        let key = Plaintext::from_str("0field").unwrap();
+        let value = Value::from_str("1u64").unwrap();
+        finalize_store.update_key_value(&program_id, &mapping_name, key, value).unwrap();
+        assert_ne!(finalize_store.operations_replay_root().unwrap(), expected);
+    }
+
     #[test]
     fn test_initialize_insert_remove() {
         // Initialize a program ID and mapping name.
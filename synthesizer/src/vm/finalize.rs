@@ -13,7 +13,9 @@
 // limitations under the License.
 
 use super::*;
-use crate::{ConfirmedTransaction, Transactions};
+use crate::{ConfirmedTransaction, FinalizeOperation, FinalizeStorage, Transactions};
+
+use std::collections::HashMap;
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum FinalizeMode {
@@ -63,17 +65,88 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         Ok(confirmed_transactions.into_iter().collect())
     }
 
+    /// Returns the finalize operations that the given pending transactions would produce in the next block,
+    /// keyed by transaction ID. Rejected transactions map to an empty list of finalize operations.
+    ///
+    /// Note: This method performs a **dry-run** of finalize, and does not verify the transactions.
+    #[inline]
+    pub fn pending_finalize_operations(
+        &self,
+        transactions: &[Transaction<N>],
+    ) -> Result<HashMap<N::TransactionID, Vec<FinalizeOperation<N>>>> {
+        // Construct the finalize state for the next block.
+        let next_height = match self.block_store().heights().max() {
+            Some(height) => (*height).saturating_add(1),
+            None => 0,
+        };
+        let state = FinalizeGlobalState::new(next_height);
+
+        // Performs a **dry-run** over the list of transactions.
+        let confirmed_transactions = self.atomic_speculate(state, transactions.iter())?;
+
+        // Return the finalize operations for each transaction.
+        Ok(transactions
+            .iter()
+            .zip_eq(confirmed_transactions)
+            .map(|(transaction, confirmed)| {
+                (transaction.id(), confirmed.finalize_operations().cloned().unwrap_or_default())
+            })
+            .collect())
+    }
+
     /// Finalizes the given transactions into the VM.
     #[inline]
     pub fn finalize(&self, state: FinalizeGlobalState, transactions: &Transactions<N>) -> Result<()> {
         let timer = timer!("VM::finalize");
 
         // Performs a **real-run** of finalize over the list of transactions.
-        self.atomic_finalize(state, transactions)?;
+        self.atomic_finalize(self.finalize_store(), state, transactions, true)?;
 
         finish!(timer, "Finished real-run of finalize");
         Ok(())
     }
+
+    /// Finalizes the transactions in the given block into the given finalize store.
+    /// If `add_stacks` is `true`, the programs deployed in the block are added to the VM,
+    /// which is required to finalize any later block that executes them.
+    ///
+    /// Note: This method does not verify the block, nor add it to the block store.
+    /// It is intended for clients that trust the block, and only need to update their view of the state.
+    #[inline]
+    pub fn apply_block_finalize<P: FinalizeStorage<N>>(
+        &self,
+        block: &Block<N>,
+        store: &FinalizeStore<N, P>,
+        add_stacks: bool,
+    ) -> Result<()> {
+        let timer = timer!("VM::apply_block_finalize");
+
+        // Construct the finalize state.
+        let state = FinalizeGlobalState::new(block.height());
+        // Performs a **real-run** of finalize over the transactions in the block.
+        self.atomic_finalize(store, state, block.transactions(), add_stacks)?;
+
+        finish!(timer, "Finished real-run of finalize for block {}", block.height());
+        Ok(())
+    }
+
+    /// Finalizes the transactions in the given block into the given finalize store,
+    /// and returns the finalize operations of the accepted transactions, in order.
+    ///
+    /// Note: This method does not verify the block, nor add it to the block store (see `apply_block_finalize`).
+    /// On success, the finalize operations are guaranteed to match those that were computed during finalize.
+    #[inline]
+    pub fn finalize_block<P: FinalizeStorage<N>>(
+        &self,
+        block: &Block<N>,
+        store: &FinalizeStore<N, P>,
+        add_stacks: bool,
+    ) -> Result<Vec<FinalizeOperation<N>>> {
+        // Finalize the block, which ensures the finalize operations in the block are correct.
+        self.apply_block_finalize(block, store, add_stacks)?;
+        // Return the finalize operations.
+        Ok(block.transactions().iter().filter_map(|tx| tx.finalize_operations()).flatten().cloned().collect())
+    }
 }
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
@@ -177,21 +250,25 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         })
     }
 
-    /// Performs atomic finalization over a list of transactions.
+    /// Performs atomic finalization over a list of transactions, into the given finalize store.
+    /// If `add_stacks` is `true`, the stacks of the deployed programs are added to the process.
     #[inline]
-    fn atomic_finalize(&self, state: FinalizeGlobalState, transactions: &Transactions<N>) -> Result<()> {
+    fn atomic_finalize<P: FinalizeStorage<N>>(
+        &self,
+        store: &FinalizeStore<N, P>,
+        state: FinalizeGlobalState,
+        transactions: &Transactions<N>,
+        add_stacks: bool,
+    ) -> Result<()> {
         let timer = timer!("VM::atomic_finalize");
 
         // Perform the finalize operation on the preset finalize mode.
-        atomic_finalize!(self.finalize_store(), FinalizeMode::RealRun, {
+        atomic_finalize!(store, FinalizeMode::RealRun, {
             // Acquire the write lock on the process.
             // Note: Due to the highly-sensitive nature of processing all `finalize` calls,
             // we choose to acquire the write lock for the entire duration of this atomic batch.
             let mut process = self.process.write();
 
-            // Retrieve the finalize store.
-            let store = self.finalize_store();
-
             // Initialize a list for the deployed stacks.
             let mut stacks = Vec::new();
 
@@ -311,7 +388,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             /* Start the commit process. */
 
             // Commit all of the stacks to the process.
-            if !stacks.is_empty() {
+            if add_stacks && !stacks.is_empty() {
                 stacks.into_iter().for_each(|stack| process.add_stack(stack))
             }
 
@@ -326,7 +403,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 mod tests {
     use super::*;
     use crate::{
-        store::helpers::memory::ConsensusMemory,
+        store::helpers::memory::{ConsensusMemory, FinalizeMemory},
         vm::{test_helpers, test_helpers::sample_finalize_state},
         Block,
         Header,
@@ -784,6 +861,401 @@ finalize transfer_public:
         }
     }
 
+    #[test]
+    fn test_pending_finalize_operations() {
+        let rng = &mut TestRng::default();
+
+        // Sample a private key and address for the caller.
+        let caller_private_key = test_helpers::sample_genesis_private_key(rng);
+        let caller_address = Address::try_from(&caller_private_key).unwrap();
+
+        // Sample a private key and address for the recipient.
+        let recipient_private_key = PrivateKey::new(rng).unwrap();
+        let recipient_address = Address::try_from(&recipient_private_key).unwrap();
+
+        // Initialize the vm.
+        let vm = test_helpers::sample_vm_with_genesis_block(rng);
+
+        // Deploy a new program.
+        let genesis =
+            vm.block_store().get_block(&vm.block_store().get_block_hash(0).unwrap().unwrap()).unwrap().unwrap();
+
+        // Get the unspent records.
+        let mut unspent_records = genesis
+            .transitions()
+            .cloned()
+            .flat_map(Transition::into_records)
+            .map(|(_, record)| record)
+            .collect::<Vec<_>>();
+
+        // Construct the deployment block.
+        let (program_id, deployment_block) =
+            new_program_deployment(&vm, &caller_private_key, &genesis, &mut unspent_records, rng).unwrap();
+
+        // Add the deployment block to the VM.
+        vm.add_next_block(&deployment_block).unwrap();
+
+        // Generate more records to use for the next block.
+        let splits_block =
+            generate_splits(&vm, &caller_private_key, &deployment_block, &mut unspent_records, rng).unwrap();
+
+        // Add the splits block to the VM.
+        vm.add_next_block(&splits_block).unwrap();
+
+        // Construct the initial mint.
+        let initial_mint =
+            sample_mint_public(&vm, caller_private_key, &program_id, caller_address, 20, &mut unspent_records, rng);
+        let initial_mint_block =
+            sample_next_block(&vm, &caller_private_key, &[initial_mint], &splits_block, &mut unspent_records, rng)
+                .unwrap();
+
+        // Add the block to the vm.
+        vm.add_next_block(&initial_mint_block).unwrap();
+
+        // Construct a mint and a transfer.
+        let mint_10 =
+            sample_mint_public(&vm, caller_private_key, &program_id, caller_address, 10, &mut unspent_records, rng);
+        let transfer_10 = sample_transfer_public(
+            &vm,
+            caller_private_key,
+            &program_id,
+            recipient_address,
+            10,
+            &mut unspent_records,
+            rng,
+        );
+
+        // Compute the pending finalize operations.
+        let transactions = [mint_10.clone(), transfer_10.clone()];
+        let operations = vm.pending_finalize_operations(&transactions).unwrap();
+        assert_eq!(operations.len(), 2);
+
+        // Ensure the mint updates the caller balance, and the transfer updates the caller and recipient balances.
+        assert_eq!(operations[&mint_10.id()].len(), 1);
+        assert_eq!(operations[&transfer_10.id()].len(), 2);
+
+        // Ensure the finalize operations match the speculated finalize operations.
+        let confirmed_transactions = vm.atomic_speculate(sample_finalize_state(1), transactions.iter()).unwrap();
+        for confirmed in confirmed_transactions {
+            assert_eq!(Some(&operations[&confirmed.transaction().id()]), confirmed.finalize_operations());
+        }
+
+        // Ensure the caller balance in the finalize store is unchanged.
+        let program_id = ProgramID::from_str(&program_id).unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        let key = Plaintext::from(Literal::Address(caller_address));
+        let balance = vm.finalize_store().get_value_speculative(&program_id, &mapping_name, &key).unwrap();
+        assert_eq!(balance, Some(Value::from_str("20u64").unwrap()));
+    }
+
+    #[test]
+    fn test_apply_block_finalize() {
+        let rng = &mut TestRng::default();
+
+        // Sample a private key and address for the caller.
+        let caller_private_key = test_helpers::sample_genesis_private_key(rng);
+        let caller_address = Address::try_from(&caller_private_key).unwrap();
+
+        // Initialize the vm, which adds the blocks in full.
+        let vm = test_helpers::sample_vm_with_genesis_block(rng);
+        // Initialize the light vm, which only applies the finalize of each block.
+        let light_vm = test_helpers::sample_vm_with_genesis_block(rng);
+
+        // Get the genesis block.
+        let genesis =
+            vm.block_store().get_block(&vm.block_store().get_block_hash(0).unwrap().unwrap()).unwrap().unwrap();
+
+        // Get the unspent records.
+        let mut unspent_records = genesis
+            .transitions()
+            .cloned()
+            .flat_map(Transition::into_records)
+            .map(|(_, record)| record)
+            .collect::<Vec<_>>();
+
+        // Construct the deployment block.
+        let (program_id, deployment_block) =
+            new_program_deployment(&vm, &caller_private_key, &genesis, &mut unspent_records, rng).unwrap();
+        let program_id = ProgramID::from_str(&program_id).unwrap();
+
+        // Add the deployment block to the vm, and apply its finalize to the light vm.
+        vm.add_next_block(&deployment_block).unwrap();
+        light_vm.apply_block_finalize(&deployment_block, light_vm.finalize_store(), true).unwrap();
+
+        // Ensure the program is deployed in both vms.
+        assert!(light_vm.contains_program(&program_id));
+        assert!(light_vm.finalize_store().contains_program_confirmed(&program_id).unwrap());
+        // Ensure the deployment block is not added to the light vm.
+        assert!(!light_vm.block_store().contains_block_hash(&deployment_block.hash()).unwrap());
+
+        // Construct the mint block.
+        let mint = sample_mint_public(
+            &vm,
+            caller_private_key,
+            &program_id.to_string(),
+            caller_address,
+            20,
+            &mut unspent_records,
+            rng,
+        );
+        let mint_block =
+            sample_next_block(&vm, &caller_private_key, &[mint], &deployment_block, &mut unspent_records, rng).unwrap();
+
+        // Add the mint block to the vm, and apply its finalize to the light vm.
+        vm.add_next_block(&mint_block).unwrap();
+        light_vm.apply_block_finalize(&mint_block, light_vm.finalize_store(), true).unwrap();
+
+        // Ensure the finalize stores match.
+        let mapping_name = Identifier::from_str("account").unwrap();
+        let key = Plaintext::from(Literal::Address(caller_address));
+        let expected = vm.finalize_store().get_value_speculative(&program_id, &mapping_name, &key).unwrap();
+        let candidate = light_vm.finalize_store().get_value_speculative(&program_id, &mapping_name, &key).unwrap();
+        assert_eq!(candidate, expected);
+        assert_eq!(candidate, Some(Value::from_str("20u64").unwrap()));
+    }
+
+    #[test]
+    fn test_finalize_block() {
+        let rng = &mut TestRng::default();
+
+        // Sample a private key and address for the caller.
+        let caller_private_key = test_helpers::sample_genesis_private_key(rng);
+        let caller_address = Address::try_from(&caller_private_key).unwrap();
+
+        // Initialize the vm, which adds the blocks in full.
+        let vm = test_helpers::sample_vm_with_genesis_block(rng);
+        // Initialize the light vm, which only finalizes each block.
+        let light_vm = test_helpers::sample_vm_with_genesis_block(rng);
+
+        // Get the genesis block.
+        let genesis =
+            vm.block_store().get_block(&vm.block_store().get_block_hash(0).unwrap().unwrap()).unwrap().unwrap();
+
+        // Get the unspent records.
+        let mut unspent_records = genesis
+            .transitions()
+            .cloned()
+            .flat_map(Transition::into_records)
+            .map(|(_, record)| record)
+            .collect::<Vec<_>>();
+
+        // Returns the finalize operations that were checked when adding the given block.
+        let expected_operations = |block: &Block<CurrentNetwork>| -> Vec<FinalizeOperation<CurrentNetwork>> {
+            block.transactions().iter().flat_map(|tx| tx.finalize_operations().cloned().unwrap_or_default()).collect()
+        };
+
+        // Construct the deployment block.
+        let (program_id, deployment_block) =
+            new_program_deployment(&vm, &caller_private_key, &genesis, &mut unspent_records, rng).unwrap();
+
+        // Add the deployment block to the vm, and finalize it in the light vm.
+        vm.add_next_block(&deployment_block).unwrap();
+        let operations = light_vm.finalize_block(&deployment_block, light_vm.finalize_store(), true).unwrap();
+        assert_eq!(operations, expected_operations(&deployment_block));
+
+        // Construct the mint block.
+        let mint =
+            sample_mint_public(&vm, caller_private_key, &program_id, caller_address, 20, &mut unspent_records, rng);
+        let mint_block =
+            sample_next_block(&vm, &caller_private_key, &[mint], &deployment_block, &mut unspent_records, rng).unwrap();
+
+        // Add the mint block to the vm, and finalize it in the light vm.
+        vm.add_next_block(&mint_block).unwrap();
+        let operations = light_vm.finalize_block(&mint_block, light_vm.finalize_store(), true).unwrap();
+        assert!(!operations.is_empty());
+        assert_eq!(operations, expected_operations(&mint_block));
+
+        // Ensure finalizing the mint block again fails, as its finalize operations no longer match.
+        assert!(light_vm.finalize_block(&mint_block, light_vm.finalize_store(), true).is_err());
+    }
+
+    #[test]
+    fn test_finalize_block_into_separate_store() {
+        let rng = &mut TestRng::default();
+
+        // Sample a private key and address for the caller.
+        let caller_private_key = test_helpers::sample_genesis_private_key(rng);
+        let caller_address = Address::try_from(&caller_private_key).unwrap();
+
+        // Initialize the vm, which adds the blocks in full.
+        let vm = test_helpers::sample_vm_with_genesis_block(rng);
+        // Initialize the light vm, which only finalizes each block into a separate store.
+        let light_vm = test_helpers::sample_vm_with_genesis_block(rng);
+        let store = FinalizeStore::<CurrentNetwork, FinalizeMemory<CurrentNetwork>>::open(None).unwrap();
+
+        // Get the genesis block.
+        let genesis =
+            vm.block_store().get_block(&vm.block_store().get_block_hash(0).unwrap().unwrap()).unwrap().unwrap();
+
+        // Get the unspent records.
+        let mut unspent_records = genesis
+            .transitions()
+            .cloned()
+            .flat_map(Transition::into_records)
+            .map(|(_, record)| record)
+            .collect::<Vec<_>>();
+
+        // Construct the deployment block.
+        let (program_id, deployment_block) =
+            new_program_deployment(&vm, &caller_private_key, &genesis, &mut unspent_records, rng).unwrap();
+        vm.add_next_block(&deployment_block).unwrap();
+
+        // Construct the mint block.
+        let mint =
+            sample_mint_public(&vm, caller_private_key, &program_id, caller_address, 20, &mut unspent_records, rng);
+        let mint_block =
+            sample_next_block(&vm, &caller_private_key, &[mint], &deployment_block, &mut unspent_records, rng).unwrap();
+        vm.add_next_block(&mint_block).unwrap();
+
+        let program_id = ProgramID::from_str(&program_id).unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        let key = Plaintext::from(Literal::Address(caller_address));
+
+        // Finalize the deployment block into a separate store, without adding the program to the light vm.
+        let skipped_store = FinalizeStore::<CurrentNetwork, FinalizeMemory<CurrentNetwork>>::open(None).unwrap();
+        light_vm.apply_block_finalize(&deployment_block, &skipped_store, false).unwrap();
+        assert!(skipped_store.contains_program_confirmed(&program_id).unwrap());
+        assert!(!light_vm.contains_program(&program_id));
+        // Ensure the mint block can not be finalized, as the light vm does not contain the program.
+        assert!(light_vm.apply_block_finalize(&mint_block, &skipped_store, false).is_err());
+
+        // Finalize the deployment block into the separate store, adding the program to the light vm.
+        let operations = light_vm.finalize_block(&deployment_block, &store, true).unwrap();
+        assert!(!operations.is_empty());
+        assert!(store.contains_program_confirmed(&program_id).unwrap());
+        assert!(light_vm.contains_program(&program_id));
+        // Ensure the program is not initialized in the finalize store of the light vm.
+        assert!(!light_vm.finalize_store().contains_program_confirmed(&program_id).unwrap());
+
+        // Finalize the mint block into the separate store.
+        let operations = light_vm.finalize_block(&mint_block, &store, true).unwrap();
+        assert_eq!(operations.len(), 1);
+
+        // Ensure the separate store matches the finalize store of the vm.
+        let expected = vm.finalize_store().get_value_speculative(&program_id, &mapping_name, &key).unwrap();
+        let candidate = store.get_value_speculative(&program_id, &mapping_name, &key).unwrap();
+        assert_eq!(candidate, expected);
+        assert_eq!(candidate, Some(Value::from_str("20u64").unwrap()));
+    }
+
+    #[test]
+    fn test_finalize_block_fails_on_invalid_command() {
+        let rng = &mut TestRng::default();
+
+        // Sample a private key and address for the caller.
+        let caller_private_key = test_helpers::sample_genesis_private_key(rng);
+        let caller_address = Address::try_from(&caller_private_key).unwrap();
+
+        // Sample an address for the recipient.
+        let recipient_address = Address::try_from(&PrivateKey::new(rng).unwrap()).unwrap();
+
+        // Initialize the vm, which adds the blocks in full.
+        let vm = test_helpers::sample_vm_with_genesis_block(rng);
+        // Initialize the light vm, which only finalizes each block into a separate store.
+        let light_vm = test_helpers::sample_vm_with_genesis_block(rng);
+        let store = FinalizeStore::<CurrentNetwork, FinalizeMemory<CurrentNetwork>>::open(None).unwrap();
+
+        // Get the genesis block.
+        let genesis =
+            vm.block_store().get_block(&vm.block_store().get_block_hash(0).unwrap().unwrap()).unwrap().unwrap();
+
+        // Get the unspent records.
+        let mut unspent_records = genesis
+            .transitions()
+            .cloned()
+            .flat_map(Transition::into_records)
+            .map(|(_, record)| record)
+            .collect::<Vec<_>>();
+
+        // Construct the deployment block.
+        let (program_id, deployment_block) =
+            new_program_deployment(&vm, &caller_private_key, &genesis, &mut unspent_records, rng).unwrap();
+        vm.add_next_block(&deployment_block).unwrap();
+
+        // Construct the mint block.
+        let mint =
+            sample_mint_public(&vm, caller_private_key, &program_id, caller_address, 20, &mut unspent_records, rng);
+        let mint_block =
+            sample_next_block(&vm, &caller_private_key, &[mint], &deployment_block, &mut unspent_records, rng).unwrap();
+        vm.add_next_block(&mint_block).unwrap();
+
+        // Construct the transfer block, which is accepted, as the caller has a balance of 20.
+        let transfer = sample_transfer_public(
+            &vm,
+            caller_private_key,
+            &program_id,
+            recipient_address,
+            10,
+            &mut unspent_records,
+            rng,
+        );
+        let transfer_block =
+            sample_next_block(&vm, &caller_private_key, &[transfer], &mint_block, &mut unspent_records, rng).unwrap();
+        assert!(transfer_block.transactions().iter().all(|confirmed| confirmed.is_accepted()));
+
+        // Finalize the deployment block into the separate store, but skip the mint block.
+        light_vm.finalize_block(&deployment_block, &store, true).unwrap();
+
+        // Ensure finalizing the transfer block fails, as the `sub` command underflows the empty caller balance.
+        assert!(light_vm.finalize_block(&transfer_block, &store, true).is_err());
+
+        // Ensure the separate store is unchanged.
+        let program_id = ProgramID::from_str(&program_id).unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        for address in [caller_address, recipient_address] {
+            let key = Plaintext::from(Literal::Address(address));
+            assert!(store.get_value_speculative(&program_id, &mapping_name, &key).unwrap().is_none());
+        }
+    }
+
+    #[test]
+    fn test_operations_replay_root() {
+        let rng = &mut TestRng::default();
+
+        // Sample a private key and address for the caller.
+        let caller_private_key = test_helpers::sample_genesis_private_key(rng);
+        let caller_address = Address::try_from(&caller_private_key).unwrap();
+
+        // Initialize the vm, which adds the blocks in full.
+        let vm = test_helpers::sample_vm_with_genesis_block(rng);
+        // Initialize the light vm, which only finalizes each block into an empty store.
+        let light_vm = test_helpers::sample_vm_with_genesis_block(rng);
+        let store = FinalizeStore::<CurrentNetwork, FinalizeMemory<CurrentNetwork>>::open(None).unwrap();
+
+        // Get the genesis block.
+        let genesis =
+            vm.block_store().get_block(&vm.block_store().get_block_hash(0).unwrap().unwrap()).unwrap().unwrap();
+
+        // Get the unspent records.
+        let mut unspent_records = genesis
+            .transitions()
+            .cloned()
+            .flat_map(Transition::into_records)
+            .map(|(_, record)| record)
+            .collect::<Vec<_>>();
+
+        // Construct the deployment block, and finalize it into the empty store.
+        let (program_id, deployment_block) =
+            new_program_deployment(&vm, &caller_private_key, &genesis, &mut unspent_records, rng).unwrap();
+        vm.add_next_block(&deployment_block).unwrap();
+        light_vm.finalize_block(&deployment_block, &store, true).unwrap();
+
+        // Ensure the root matches the finalize root in the header, as the block initializes the entire state.
+        assert_eq!(store.operations_replay_root().unwrap(), deployment_block.header().finalize_root());
+
+        // Construct the mint block, and finalize it into the store.
+        let mint =
+            sample_mint_public(&vm, caller_private_key, &program_id, caller_address, 20, &mut unspent_records, rng);
+        let mint_block =
+            sample_next_block(&vm, &caller_private_key, &[mint], &deployment_block, &mut unspent_records, rng).unwrap();
+        vm.add_next_block(&mint_block).unwrap();
+        light_vm.finalize_block(&mint_block, &store, true).unwrap();
+
+        // Ensure the root does not match the finalize root in the header,
+        // as the header only commits to the finalize operations of the mint block.
+        assert_ne!(store.operations_replay_root().unwrap(), mint_block.header().finalize_root());
+    }
+
     #[test]
     fn test_finalize_catch_halt() {
         let rng = &mut TestRng::default();
@@ -91,6 +91,21 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         self.process.read().contains_program(program_id)
     }
 
+    /// Returns the program for the given program ID.
+    /// This method checks the process first, and falls back to the deployments in the block store.
+    #[inline]
+    pub fn get_program(&self, program_id: &ProgramID<N>) -> Result<Program<N>> {
+        // Check if the program is loaded in the process.
+        if let Ok(program) = self.process.read().get_program(program_id) {
+            return Ok(program.clone());
+        }
+        // Otherwise, retrieve the program from the block store.
+        match self.block_store().get_program(program_id)? {
+            Some(program) => Ok(program),
+            None => bail!("Program '{program_id}' does not exist"),
+        }
+    }
+
     /// Returns the process.
     #[inline]
     pub fn process(&self) -> Arc<RwLock<Process<N>>> {
@@ -654,4 +669,90 @@ finalize getter:
             sample_next_block(&vm, &caller_private_key, &[first_execution, second_execution], rng).unwrap();
         vm.add_next_block(&execution_block).unwrap();
     }
+
+    #[test]
+    fn test_get_program() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the VM.
+        let vm = sample_vm_with_genesis_block(rng);
+        // Initialize a new caller.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+
+        // Ensure 'credits.aleo' is found.
+        let credits = ProgramID::<CurrentNetwork>::from_str("credits.aleo").unwrap();
+        assert_eq!(vm.get_program(&credits).unwrap(), Program::credits().unwrap());
+
+        // Deploy the program.
+        let transaction = sample_deployment_transaction(rng);
+        let program = transaction.deployment().unwrap().program().clone();
+        // Ensure the program is not found before it is deployed.
+        assert!(vm.get_program(program.id()).is_err());
+
+        // Add the deployment to a block and update the VM.
+        let block = sample_next_block(&vm, &caller_private_key, &[transaction], rng).unwrap();
+        vm.add_next_block(&block).unwrap();
+
+        // Ensure the program is found.
+        assert_eq!(vm.get_program(program.id()).unwrap(), program);
+
+        // Ensure an undeployed program is not found.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("undeployed.aleo").unwrap();
+        assert!(vm.get_program(&program_id).is_err());
+    }
+
+    #[test]
+    fn test_get_program_from_block_store() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the VM.
+        let vm = sample_vm_with_genesis_block(rng);
+        // Initialize a new caller.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+
+        // Deploy the program.
+        let transaction = sample_deployment_transaction(rng);
+        let program = transaction.deployment().unwrap().program().clone();
+
+        // Add the deployment to a block and update the VM.
+        let block = sample_next_block(&vm, &caller_private_key, &[transaction], rng).unwrap();
+        vm.add_next_block(&block).unwrap();
+
+        // Initialize a second VM on the same store, whose process has not loaded the deployed program.
+        let other_vm = VM { process: Arc::new(RwLock::new(Process::load().unwrap())), store: vm.store.clone() };
+        assert!(!other_vm.contains_program(program.id()));
+
+        // Ensure the program is retrieved from the block store.
+        assert_eq!(other_vm.get_program(program.id()).unwrap(), program);
+    }
+
+    #[test]
+    fn test_compute_state_root() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the VM.
+        let vm = sample_vm_with_genesis_block(rng);
+        // Initialize a new caller.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        // Initialize the genesis block.
+        let genesis = sample_genesis_block(rng);
+
+        // Ensure the state root matches the current state root of the VM.
+        let state_root = crate::block::compute_state_root(&[*genesis.header()]).unwrap();
+        assert_eq!(state_root, vm.block_store().current_state_root());
+
+        // Construct the next block.
+        let transaction = sample_fee_transaction(rng);
+        let block = sample_next_block(&vm, &caller_private_key, &[transaction], rng).unwrap();
+        // Ensure the state root matches the one stored in the next block header.
+        assert_eq!(*state_root, block.previous_state_root());
+        vm.add_next_block(&block).unwrap();
+
+        // Ensure the state root over both headers matches the current state root of the VM.
+        let state_root = crate::block::compute_state_root(&[*genesis.header(), *block.header()]).unwrap();
+        assert_eq!(state_root, vm.block_store().current_state_root());
+
+        // Ensure out-of-order block headers are rejected.
+        assert!(crate::block::compute_state_root(&[*block.header()]).is_err());
+    }
 }
@@ -14,6 +14,9 @@
 
 use super::*;
 
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     /// Returns `true` if the transaction is valid.
     pub fn verify_transaction(&self, transaction: &Transaction<N>, rejected_id: Option<Field<N>>) -> bool {
@@ -28,7 +31,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
     /// Returns `true` if the deployment is valid.
     pub fn verify_deployment(&self, deployment: &Deployment<N>) -> bool {
-        match self.check_deployment(deployment) {
+        match self.check_deployment_certificates(deployment) {
             Ok(_) => true,
             Err(error) => {
                 warn!("{error}");
@@ -59,6 +62,35 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         }
     }
 
+    /// Verifies the transactions in the given block in parallel. On failure, returns an error.
+    /// The deployments are verified first, followed by the executions and fees, each as a separate parallel batch.
+    /// Note: This does *not* verify the block header, nor does it check the transactions against the ledger.
+    pub fn verify_block_parallel(&self, block: &Block<N>) -> Result<()> {
+        let timer = timer!("VM::verify_block_parallel");
+
+        // Partition the transactions into deployments and executions (including fees).
+        let (deployments, executions): (Vec<_>, Vec<_>) =
+            block.transactions().iter().partition(|confirmed| confirmed.is_deploy());
+
+        for batch in [deployments, executions] {
+            cfg_iter!(batch).try_for_each(|confirmed| {
+                // Compute the rejected ID, if the transaction is rejected.
+                let rejected_id = match confirmed {
+                    ConfirmedTransaction::AcceptedDeploy(..) | ConfirmedTransaction::AcceptedExecute(..) => None,
+                    ConfirmedTransaction::RejectedDeploy(_, _, deployment) => Some(deployment.to_deployment_id()?),
+                    ConfirmedTransaction::RejectedExecute(_, _, execution) => Some(execution.to_execution_id()?),
+                };
+                // Verify the transaction.
+                self.check_transaction(confirmed.transaction(), rejected_id)
+            })?;
+            lap!(timer, "Verify a batch of transactions");
+        }
+
+        finish!(timer);
+
+        Ok(())
+    }
+
     /// Verifies the transaction in the VM. On failure, returns an error.
     #[inline]
     pub fn check_transaction(&self, transaction: &Transaction<N>, rejected_id: Option<Field<N>>) -> Result<()> {
@@ -117,7 +149,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 // Verify the fee.
                 self.check_fee(fee, deployment_id)?;
                 // Verify the deployment.
-                self.check_deployment(deployment)?;
+                self.check_deployment_certificates(deployment)?;
             }
             Transaction::Execute(id, execution, fee) => {
                 // Ensure the rejected ID is not present.
@@ -157,9 +189,36 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         Ok(())
     }
 
+    /// Checks that the program in the given deployment does not exist, that the given fee covers the deployment cost,
+    /// and that the deployment is valid. On failure, returns an error listing each failed check.
+    /// Note: This does *not* verify the fee itself; use `check_fee` for that.
+    pub fn check_deployment(&self, deployment: &Deployment<N>, fee: &Fee<N>) -> Result<()> {
+        let mut errors = Vec::new();
+
+        // Ensure the program does not already exist in the store.
+        match self.transaction_store().contains_program_id(deployment.program_id()) {
+            Ok(false) => (),
+            Ok(true) => errors.push(format!("Program '{}' already exists", deployment.program_id())),
+            Err(error) => errors.push(error.to_string()),
+        }
+        // Ensure the fee covers the deployment cost.
+        if let Err(error) = self.process.read().verify_deployment_fee(deployment, fee) {
+            errors.push(error.to_string());
+        }
+        // Ensure the deployment is valid.
+        if let Err(error) = self.check_deployment_certificates(deployment) {
+            errors.push(error.to_string());
+        }
+
+        match errors.is_empty() {
+            true => Ok(()),
+            false => bail!("Failed to check the deployment of '{}': {}", deployment.program_id(), errors.join("; ")),
+        }
+    }
+
     /// Verifies the given deployment. On failure, returns an error.
     #[inline]
-    fn check_deployment(&self, deployment: &Deployment<N>) -> Result<()> {
+    fn check_deployment_certificates(&self, deployment: &Deployment<N>) -> Result<()> {
         let timer = timer!("VM::verify_deployment");
 
         // Compute the core logic.
@@ -261,6 +320,70 @@ mod tests {
         assert!(vm.verify_transaction(&execution_transaction, None));
     }
 
+    #[test]
+    fn test_verify_block_parallel() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new caller.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let caller_view_key = ViewKey::try_from(&caller_private_key).unwrap();
+
+        // Initialize the genesis block, and prepare the unspent records.
+        let genesis = crate::vm::test_helpers::sample_genesis_block(rng);
+        let mut records =
+            genesis.records().map(|(_, record)| record.decrypt(&caller_view_key).unwrap()).collect::<Vec<_>>();
+
+        // Initialize the VM.
+        let vm = crate::vm::test_helpers::sample_vm();
+        vm.add_next_block(&genesis).unwrap();
+
+        // Deploy the program.
+        let program = crate::vm::test_helpers::sample_program();
+        let mut transactions =
+            vec![vm.deploy(&caller_private_key, &program, (records.pop().unwrap(), 10), None, rng).unwrap()];
+        // Split four of the records.
+        for _ in 0..4 {
+            let inputs = [Value::Record(records.pop().unwrap()), Value::from_str("1000000u64").unwrap()];
+            let transaction =
+                vm.execute(&caller_private_key, ("credits.aleo", "split"), inputs.iter(), None, None, rng).unwrap();
+            transactions.push(transaction);
+        }
+
+        // Construct the block.
+        let block =
+            crate::vm::test_helpers::sample_next_block(&vm, &caller_private_key, &transactions, rng).unwrap();
+        assert_eq!(block.transactions().len(), 5);
+        assert_eq!(block.transactions().iter().filter(|confirmed| confirmed.is_deploy()).count(), 1);
+        assert_eq!(block.transactions().iter().filter(|confirmed| confirmed.is_execute()).count(), 4);
+
+        // Ensure the block verifies in parallel.
+        vm.verify_block_parallel(&block).unwrap();
+        // Ensure the result matches the serial verification of each transaction.
+        assert!(block.transactions().iter().all(|confirmed| vm.verify_transaction(confirmed, None)));
+
+        // Tamper with the ID of the last execution.
+        let transactions = block
+            .transactions()
+            .iter()
+            .cloned()
+            .map(|confirmed| match confirmed {
+                ConfirmedTransaction::AcceptedExecute(index, Transaction::Execute(id, execution, fee), finalize)
+                    if index == 4 =>
+                {
+                    assert_ne!(*id, Field::zero());
+                    let tampered = Transaction::Execute(Field::<CurrentNetwork>::zero().into(), execution, fee);
+                    ConfirmedTransaction::AcceptedExecute(index, tampered, finalize)
+                }
+                confirmed => confirmed,
+            })
+            .collect();
+        let tampered_block =
+            Block::new(&caller_private_key, block.previous_hash(), *block.header(), transactions, None, rng).unwrap();
+
+        // Ensure the tampered block fails to verify.
+        assert!(vm.verify_block_parallel(&tampered_block).is_err());
+    }
+
     #[test]
     fn test_verify_deployment() {
         let rng = &mut TestRng::default();
@@ -273,16 +396,65 @@ mod tests {
         let deployment = vm.deploy_raw(&program, rng).unwrap();
 
         // Ensure the deployment is valid.
-        assert!(vm.check_deployment(&deployment).is_ok());
+        assert!(vm.check_deployment_certificates(&deployment).is_ok());
         assert!(vm.verify_deployment(&deployment));
 
         // Ensure that deserialization doesn't break the transaction verification.
         let serialized_deployment = deployment.to_string();
         let deployment_transaction: Deployment<CurrentNetwork> = serde_json::from_str(&serialized_deployment).unwrap();
-        assert!(vm.check_deployment(&deployment_transaction).is_ok());
+        assert!(vm.check_deployment_certificates(&deployment_transaction).is_ok());
         assert!(vm.verify_deployment(&deployment_transaction));
     }
 
+    #[test]
+    fn test_check_deployment() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new caller.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let caller_view_key = ViewKey::try_from(&caller_private_key).unwrap();
+
+        // Initialize the genesis block, and prepare the fee record.
+        let genesis = crate::vm::test_helpers::sample_genesis_block(rng);
+        let records = genesis.records().collect::<indexmap::IndexMap<_, _>>();
+        let credits = records.values().next().unwrap().decrypt(&caller_view_key).unwrap();
+
+        // Initialize the VM.
+        let vm = crate::vm::test_helpers::sample_vm();
+        vm.add_next_block(&genesis).unwrap();
+
+        // Deploy the program.
+        let program = crate::vm::test_helpers::sample_program();
+        let transaction = vm.deploy(&caller_private_key, &program, (credits, 10), None, rng).unwrap();
+        let Transaction::Deploy(_, _, deployment, fee) = &transaction else { unreachable!() };
+
+        // Ensure the deployment passes all checks.
+        vm.check_deployment(deployment, fee).unwrap();
+
+        // Ensure an insufficient fee fails.
+        let insufficient_fee = crate::vm::test_helpers::sample_fee(rng);
+        let error = vm.check_deployment(deployment, &insufficient_fee).unwrap_err();
+        assert!(error.to_string().contains("Insufficient fee"));
+
+        // Ensure an invalid deployment fails.
+        let error = vm.check_deployment(&deployment.strip_certificates(), fee).unwrap_err();
+        assert!(error.to_string().contains("Deployment verification failed"));
+
+        // Add the deployment to the VM.
+        let block = crate::vm::test_helpers::sample_next_block(
+            &vm,
+            &caller_private_key,
+            std::slice::from_ref(&transaction),
+            rng,
+        )
+        .unwrap();
+        vm.add_next_block(&block).unwrap();
+
+        // Ensure a redeployment of the program fails.
+        let error = vm.check_deployment(deployment, fee).unwrap_err();
+        assert!(error.to_string().contains(&format!("Program '{}' already exists", program.id())));
+    }
+
     #[test]
     fn test_verify_execution() {
         let rng = &mut TestRng::default();